@@ -1,12 +1,22 @@
 //! Client
 //! =========
 //! This file contains the Paystack API client, and it associated endpoints.
+use crate::http::reqwest::{DEFAULT_CONNECT_TIMEOUT, DEFAULT_TIMEOUT};
 use crate::{
-    ApplePayEndpoints, CustomersEndpoints, DedicatedVirtualAccountEndpoints, HttpClient,
-    PlansEndpoints, SubaccountEndpoints, TerminalEndpoints, TransactionEndpoints,
-    TransactionSplitEndpoints, VirtualTerminalEndpoints,
+    ApplePayEndpoints, BulkChargeEndpoints, ChargeEndpoints, CustomersEndpoints,
+    DedicatedVirtualAccountEndpoints, DirectDebitEndpoints, DisputeEndpoints, HttpClient,
+    IntegrationEndpoints, MiscellaneousEndpoints, OrderEndpoints, PaymentPageEndpoints,
+    PaymentRequestEndpoints, PaystackAPIError, PlansEndpoints, ProductEndpoints, RefundEndpoints,
+    ReqwestClient, SettlementEndpoints, StorefrontEndpoints, SubaccountEndpoints,
+    SubscriptionEndpoints, TerminalEndpoints, TransactionEndpoints, TransactionSplitEndpoints,
+    TransferControlEndpoints, TransferEndpoints, TransferRecipientEndpoints, VerificationEndpoints,
+    VirtualTerminalEndpoints, PAYSTACK_BASE_URL,
 };
-use std::sync::Arc;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Client, Proxy, Url,
+};
+use std::{sync::Arc, time::Duration};
 
 /// This is the entry level struct for the paystack API.
 /// it allows for authentication of the client
@@ -29,25 +39,456 @@ pub struct PaystackClient<T: HttpClient + Default> {
     pub apple_pay: ApplePayEndpoints<T>,
     /// Plans API route
     pub plans: PlansEndpoints<T>,
+    /// Transfers API route
+    pub transfers: TransferEndpoints<T>,
+    /// Transfer Recipients API route
+    pub transfer_recipients: TransferRecipientEndpoints<T>,
+    /// Transfers Control API route
+    pub transfer_control: TransferControlEndpoints<T>,
+    /// Refunds API route
+    pub refunds: RefundEndpoints<T>,
+    /// Disputes API route
+    pub disputes: DisputeEndpoints<T>,
+    /// Subscriptions API route
+    pub subscriptions: SubscriptionEndpoints<T>,
+    /// Charge API route
+    pub charges: ChargeEndpoints<T>,
+    /// Bulk Charges API route
+    pub bulk_charges: BulkChargeEndpoints<T>,
+    /// Payment Requests API route
+    pub payment_requests: PaymentRequestEndpoints<T>,
+    /// Products API route
+    pub products: ProductEndpoints<T>,
+    /// Payment Pages API route
+    pub payment_pages: PaymentPageEndpoints<T>,
+    /// Settlements API route
+    pub settlements: SettlementEndpoints<T>,
+    /// Verification API route
+    pub verification: VerificationEndpoints<T>,
+    /// Miscellaneous API route
+    pub miscellaneous: MiscellaneousEndpoints<T>,
+    /// Integration API route
+    pub integration: IntegrationEndpoints<T>,
+    /// Direct Debit API route
+    pub direct_debit: DirectDebitEndpoints<T>,
+    /// Storefronts API route
+    pub storefronts: StorefrontEndpoints<T>,
+    /// Orders API route
+    pub orders: OrderEndpoints<T>,
 }
 
 impl<T: HttpClient + Default> PaystackClient<T> {
     pub fn new(api_key: String) -> PaystackClient<T> {
-        let http = Arc::new(T::default());
+        PaystackClient::with_http_client(api_key, T::default())
+    }
+
+    /// Creates a new PaystackClient instance that sends its requests with the given HTTP client.
+    /// This is used to configure the HTTP client, e.g. to retry failed requests with a `RetryClient`.
+    ///
+    /// # Arguments
+    /// * `api_key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new PaystackClient instance
+    pub fn with_http_client(api_key: String, http: T) -> PaystackClient<T> {
+        PaystackClient::with_base_url(api_key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new PaystackClient instance that sends its requests with the given HTTP client
+    /// to the given base URL, e.g. a local mock of the Paystack API.
+    ///
+    /// # Arguments
+    /// * `api_key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new PaystackClient instance
+    pub fn with_base_url(api_key: String, http: T, base_url: &str) -> PaystackClient<T> {
+        let base_url = base_url.trim_end_matches('/');
+        let http = Arc::new(http);
         let key = Arc::new(api_key);
         PaystackClient {
-            transactions: TransactionEndpoints::new(Arc::clone(&key), Arc::clone(&http)),
-            transaction_split: TransactionSplitEndpoints::new(Arc::clone(&key), Arc::clone(&http)),
-            subaccount: SubaccountEndpoints::new(Arc::clone(&key), Arc::clone(&http)),
-            terminal: TerminalEndpoints::new(Arc::clone(&key), Arc::clone(&http)),
-            virutal_terminal: VirtualTerminalEndpoints::new(Arc::clone(&key), Arc::clone(&http)),
-            customers: CustomersEndpoints::new(Arc::clone(&key), Arc::clone(&http)),
-            dedicated_virtual_account: DedicatedVirtualAccountEndpoints::new(
+            transactions: TransactionEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            transaction_split: TransactionSplitEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            subaccount: SubaccountEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            terminal: TerminalEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            virutal_terminal: VirtualTerminalEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            customers: CustomersEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            dedicated_virtual_account: DedicatedVirtualAccountEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            apple_pay: ApplePayEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            plans: PlansEndpoints::with_base_url(Arc::clone(&key), Arc::clone(&http), base_url),
+            transfers: TransferEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            transfer_recipients: TransferRecipientEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            transfer_control: TransferControlEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            refunds: RefundEndpoints::with_base_url(Arc::clone(&key), Arc::clone(&http), base_url),
+            disputes: DisputeEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            subscriptions: SubscriptionEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            charges: ChargeEndpoints::with_base_url(Arc::clone(&key), Arc::clone(&http), base_url),
+            bulk_charges: BulkChargeEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            payment_requests: PaymentRequestEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            products: ProductEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            payment_pages: PaymentPageEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            settlements: SettlementEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            verification: VerificationEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            miscellaneous: MiscellaneousEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            integration: IntegrationEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            direct_debit: DirectDebitEndpoints::with_base_url(
                 Arc::clone(&key),
                 Arc::clone(&http),
+                base_url,
             ),
-            apple_pay: ApplePayEndpoints::new(Arc::clone(&key), Arc::clone(&http)),
-            plans: PlansEndpoints::new(Arc::clone(&key), Arc::clone(&http)),
+            storefronts: StorefrontEndpoints::with_base_url(
+                Arc::clone(&key),
+                Arc::clone(&http),
+                base_url,
+            ),
+            orders: OrderEndpoints::with_base_url(Arc::clone(&key), Arc::clone(&http), base_url),
+        }
+    }
+}
+
+impl PaystackClient<ReqwestClient> {
+    /// Creates a builder to configure the HTTP client and base URL of a PaystackClient
+    ///
+    /// # Arguments
+    /// * `api_key` - The Paystack API key
+    ///
+    /// # Returns
+    /// A new PaystackClientBuilder instance
+    pub fn builder(api_key: String) -> PaystackClientBuilder {
+        PaystackClientBuilder::new(api_key)
+    }
+}
+
+/// Builder for a `PaystackClient` sending its requests with a configured `ReqwestClient`.
+/// Use `PaystackClient::builder` to create this object.
+#[derive(Debug, Default)]
+pub struct PaystackClientBuilder {
+    /// Paystack API Key
+    api_key: String,
+    /// Base URL of the Paystack API, defaults to `PAYSTACK_BASE_URL`
+    base_url: Option<String>,
+    /// Timeout for connecting to the API, defaults to 10 seconds
+    connect_timeout: Option<Duration>,
+    /// Timeout for a whole request, from connecting until the response body is read, defaults to 60 seconds
+    timeout: Option<Duration>,
+    /// Proxy the requests are sent through
+    proxy: Option<Proxy>,
+    /// User agent sent with every request
+    user_agent: Option<String>,
+    /// Headers sent with every request
+    default_headers: HeaderMap,
+    /// A configured reqwest client, used instead of building one
+    client: Option<Client>,
+    /// How long the responses of the miscellaneous endpoints are cached, not cached if unset
+    misc_cache_ttl: Option<Duration>,
+}
+
+impl PaystackClientBuilder {
+    /// Creates a new PaystackClientBuilder instance
+    ///
+    /// # Arguments
+    /// * `api_key` - The Paystack API key
+    ///
+    /// # Returns
+    /// A new PaystackClientBuilder instance
+    pub fn new(api_key: String) -> Self {
+        PaystackClientBuilder {
+            api_key,
+            ..Default::default()
+        }
+    }
+
+    /// Sends the requests to the given base URL instead of `PAYSTACK_BASE_URL`, e.g. a local mock.
+    /// The URL is checked when the client is built.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets the timeout for connecting to the API
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for a whole request, from connecting until the response body is read
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sends the requests through the given proxy
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Sets the user agent sent with every request
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Adds a header sent with every request
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers.insert(name, value);
+        self
+    }
+
+    /// Adds headers sent with every request
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers.extend(headers);
+        self
+    }
+
+    /// Sends the requests with a configured reqwest client instead of building one.
+    /// The client cannot be combined with the timeout, proxy, user agent and header options.
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Caches the responses of the miscellaneous endpoints e.g. the list of banks for the given duration.
+    /// See `MiscellaneousEndpoints::with_cache`.
+    pub fn misc_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.misc_cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Builds the PaystackClient
+    ///
+    /// # Returns
+    /// A Result containing the PaystackClient or an error if the base URL is invalid
+    /// or the HTTP client cannot be built
+    pub fn build(self) -> Result<PaystackClient<ReqwestClient>, PaystackAPIError> {
+        self.build_with(|http| http)
+    }
+
+    /// Builds the PaystackClient with the configured `ReqwestClient` wrapped in another HTTP client,
+    /// e.g. a `RetryClient` or a `RateLimitedClient`.
+    ///
+    /// # Arguments
+    /// * `wrap` - Function wrapping the configured `ReqwestClient`
+    ///
+    /// # Returns
+    /// A Result containing the PaystackClient or an error if the base URL is invalid
+    /// or the HTTP client cannot be built
+    pub fn build_with<T, F>(self, wrap: F) -> Result<PaystackClient<T>, PaystackAPIError>
+    where
+        T: HttpClient + Default,
+        F: FnOnce(ReqwestClient) -> T,
+    {
+        let base_url = self.base_url.as_deref().unwrap_or(PAYSTACK_BASE_URL);
+        match Url::parse(base_url) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") => {}
+            _ => {
+                return Err(PaystackAPIError::Generic(format!(
+                    "invalid base URL: {base_url}"
+                )))
+            }
+        }
+
+        let configured = self.connect_timeout.is_some()
+            || self.timeout.is_some()
+            || self.proxy.is_some()
+            || self.user_agent.is_some()
+            || !self.default_headers.is_empty();
+
+        let client = match self.client {
+            Some(_) if configured => {
+                return Err(PaystackAPIError::Generic(
+                    "timeouts, proxy, user agent and default headers cannot be set on an injected reqwest client".to_string(),
+                ))
+            }
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder()
+                    .default_headers(self.default_headers)
+                    .connect_timeout(self.connect_timeout.unwrap_or(DEFAULT_CONNECT_TIMEOUT))
+                    .timeout(self.timeout.unwrap_or(DEFAULT_TIMEOUT));
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+                if let Some(user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                builder
+                    .build()
+                    .map_err(|e| PaystackAPIError::Generic(e.to_string()))?
+            }
+        };
+
+        let http = wrap(ReqwestClient::new(client));
+        let mut paystack_client = PaystackClient::with_base_url(self.api_key, http, base_url);
+        if let Some(ttl) = self.misc_cache_ttl {
+            paystack_client.miscellaneous = paystack_client.miscellaneous.with_cache(ttl);
+        }
+
+        Ok(paystack_client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::fake::FakeClient;
+
+    #[tokio::test]
+    async fn endpoints_use_the_configured_base_url() {
+        let http = FakeClient::default();
+        let client = PaystackClient::builder("key".to_string())
+            .base_url("http://localhost:8080/")
+            .build_with(|_| http.clone())
+            .unwrap();
+
+        client.refunds.fetch_refund(1).await.unwrap();
+        client.miscellaneous.list_countries().await.unwrap();
+
+        let urls: Vec<String> = http.requests().into_iter().map(|r| r.url).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "http://localhost:8080/refund/1".to_string(),
+                "http://localhost:8080/country".to_string()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn misc_cache_ttl_caches_miscellaneous_responses() {
+        let http = FakeClient::ok(r#"{"status": true, "message": "ok", "data": []}"#);
+        let client = PaystackClient::builder("key".to_string())
+            .misc_cache_ttl(Duration::from_secs(60))
+            .build_with(|_| http.clone())
+            .unwrap();
+
+        client.miscellaneous.list_countries().await.unwrap();
+        client.miscellaneous.list_countries().await.unwrap();
+
+        assert_eq!(http.requests().len(), 1);
+    }
+
+    #[test]
+    fn can_build_client_with_options() {
+        let client = PaystackClient::builder("key".to_string())
+            .connect_timeout(Duration::from_secs(5))
+            .timeout(Duration::from_secs(30))
+            .proxy(Proxy::all("http://localhost:3128").unwrap())
+            .user_agent("paystack-rs-test")
+            .default_header(
+                HeaderName::from_static("x-request-source"),
+                HeaderValue::from_static("test"),
+            )
+            .build();
+
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn cannot_configure_an_injected_client() {
+        let client = PaystackClient::builder("key".to_string())
+            .client(Client::new())
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        assert!(matches!(client, Err(PaystackAPIError::Generic(_))));
+    }
+
+    #[test]
+    fn rejects_an_invalid_base_url() {
+        for base_url in ["localhost:8080", "not a url", "ftp://example.com"] {
+            let client = PaystackClient::builder("key".to_string())
+                .base_url(base_url)
+                .build();
+
+            assert!(matches!(client, Err(PaystackAPIError::Generic(_))));
         }
     }
 }
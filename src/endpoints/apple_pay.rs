@@ -1,10 +1,14 @@
 //! Apple Pay
-//! THe Apple Pay API allows you register your application's top-level domain or subdomain.
+//! =========
+//! The Apple Pay API allows you register your application's top-level domain or subdomain.
 
 use super::PAYSTACK_BASE_URL;
-use crate::{ApplePayResponseData, HttpClient, PaystackAPIError, PaystackResult};
+use crate::{
+    ApiError, ApplePayDomainChange, ApplePayResponseData, HttpClient, HttpError,
+    ListApplePayDomainsFilter, PaystackAPIError, PaystackResult, Response,
+};
 use serde_json::json;
-use std::{marker::PhantomData, sync::Arc};
+use std::{collections::HashSet, marker::PhantomData, sync::Arc};
 
 #[derive(Debug, Clone)]
 pub struct ApplePayEndpoints<T: HttpClient + Default> {
@@ -18,7 +22,6 @@ pub struct ApplePayEndpoints<T: HttpClient + Default> {
 
 impl<T: HttpClient + Default> ApplePayEndpoints<T> {
     /// Creates a new ApplePayEndpoints instance
-    ///Creates a new ApplePayEndpoints instance
     ///
     /// # Arguments
     /// * `key` - The Paystack API key
@@ -27,7 +30,20 @@ impl<T: HttpClient + Default> ApplePayEndpoints<T> {
     /// # Returns
     /// A new ApplePayEndpoints instance
     pub fn new(key: Arc<String>, http: Arc<T>) -> ApplePayEndpoints<T> {
-        let base_url = format!("{PAYSTACK_BASE_URL}/apple-pay/domain");
+        ApplePayEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new ApplePayEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new ApplePayEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> ApplePayEndpoints<T> {
+        let base_url = format!("{base_url}/apple-pay/domain");
         ApplePayEndpoints {
             key: key.to_string(),
             base_url,
@@ -37,30 +53,29 @@ impl<T: HttpClient + Default> ApplePayEndpoints<T> {
 
     /// Register a top-level domain or subdomain for your Apple Pay integration.
     ///
+    /// Registering a domain that is already registered is not an error, so the registered
+    /// domains can be reconciled idempotently.
+    ///
     /// # Arguments
     /// * `domain_name` - The domain name to be registered with Apple Pay
     ///
     /// # Returns
-    /// A Result containing the registration response or an error
+    /// A Result containing the domain change, reporting whether the domain was already present
     pub async fn register_domain(
         &self,
         domain_name: String,
-    ) -> PaystackResult<PhantomData<String>> {
+    ) -> Result<ApplePayDomainChange, PaystackAPIError> {
         let url = &self.base_url;
         let body = json!({
             "domainName": domain_name
         });
 
-        let response = self
-            .http
-            .post(url, &self.key, &body)
-            .await
-            .map_err(|e| PaystackAPIError::ApplePay(e.to_string()))?;
-
-        let parsed_response = serde_json::from_str(&response)
-            .map_err(|e| PaystackAPIError::ApplePay(e.to_string()))?;
+        let response = self.http.post(url, &self.key, &body).await;
 
-        Ok(parsed_response)
+        // Paystack rejects domains that are already registered
+        domain_change(domain_name, response, false, |error| {
+            error.message.to_lowercase().contains("already")
+        })
     }
 
     /// Lists all domains registered on your integration
@@ -68,13 +83,44 @@ impl<T: HttpClient + Default> ApplePayEndpoints<T> {
     /// # Returns
     /// A Result containing the list of registered domains or an error
     pub async fn list_domains(&self) -> PaystackResult<ApplePayResponseData> {
+        self.list_domains_with_filter(None).await
+    }
+
+    /// Lists the domains registered on your integration, one page at a time
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to paginate the domains returned.
+    ///   It should be created with the `ListApplePayDomainsFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the list of registered domains or an error.
+    /// The cursors for the next and previous pages are in the response `meta`.
+    pub async fn list_domains_with_filter(
+        &self,
+        filter: Option<ListApplePayDomainsFilter>,
+    ) -> PaystackResult<ApplePayResponseData> {
         let url = &self.base_url;
+        let filter = filter.unwrap_or_default();
+
+        let mut query = vec![];
+        if let Some(use_cursor) = filter.use_cursor {
+            query.push(("use_cursor", use_cursor.to_string()));
+        }
+        if let Some(next) = filter.next {
+            query.push(("next", next));
+        }
+        if let Some(previous) = filter.previous {
+            query.push(("previous", previous));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
         let response = self
             .http
-            .get(url, &self.key, None)
+            .get(url, &self.key, Some(&query))
             .await
-            .map_err(|e| PaystackAPIError::ApplePay(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::ApplePay, e))?;
 
         let parsed_response = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::ApplePay(e.to_string()))?;
@@ -82,31 +128,270 @@ impl<T: HttpClient + Default> ApplePayEndpoints<T> {
         Ok(parsed_response)
     }
 
+    /// Checks if a domain is registered on your integration, going through every page of domains.
+    ///
+    /// This makes one request per page of domains, and the domains can change right after
+    /// the check. Use it to reconcile the registered domains, not before every registration.
+    ///
+    /// # Arguments
+    /// * `domain_name` - The domain name to look for
+    ///
+    /// # Returns
+    /// A Result containing `true` if the domain is registered or an error
+    pub async fn is_domain_registered(&self, domain_name: &str) -> Result<bool, PaystackAPIError> {
+        let mut next = None;
+        let mut seen_cursors = HashSet::new();
+
+        loop {
+            let filter = ListApplePayDomainsFilter {
+                use_cursor: Some(true),
+                next: next.take(),
+                previous: None,
+            };
+            let response = self.list_domains_with_filter(Some(filter)).await?;
+
+            let found = response
+                .data
+                .map(|data| data.domain_names.iter().any(|d| d.matches(domain_name)))
+                .unwrap_or(false);
+            if found {
+                return Ok(true);
+            }
+
+            // Stop at the last page, or if the API sends a cursor it already sent
+            match response.meta.and_then(|meta| meta.next) {
+                Some(cursor) if !cursor.is_empty() && seen_cursors.insert(cursor.clone()) => {
+                    next = Some(cursor)
+                }
+                _ => return Ok(false),
+            }
+        }
+    }
+
     /// Unregister a top-level domain or subdomain previously used for your Apple Pay integration.
     ///
+    /// Unregistering a domain that is not registered is not an error, so the registered
+    /// domains can be reconciled idempotently.
+    ///
     /// # Arguments
     /// * `domain_name` - The name of the domain to unregister
     ///
     /// # Returns
-    /// A result containing a success message without data.
+    /// A Result containing the domain change, reporting whether the domain was already present
     pub async fn unregister_domain(
         &self,
         domain_name: String,
-    ) -> PaystackResult<PhantomData<String>> {
+    ) -> Result<ApplePayDomainChange, PaystackAPIError> {
         let url = &self.base_url;
         let body = json!({
             "domainName": domain_name
         });
 
-        let response = self
-            .http
-            .delete(url, &self.key, &body)
+        let response = self.http.delete(url, &self.key, &body).await;
+
+        // Paystack rejects domains that are not registered
+        domain_change(domain_name, response, true, |error| {
+            let message = error.message.to_lowercase();
+            error.is_not_found()
+                || message.contains("not found")
+                || message.contains("not registered")
+        })
+    }
+}
+
+/// Builds the outcome of registering or unregistering a domain from the response of the API.
+///
+/// # Arguments
+/// * `domain_name` - The domain that was registered or unregistered
+/// * `response` - The response of the API
+/// * `unregistering` - Whether the domain was unregistered, so it was present if the request succeeded
+/// * `is_no_op` - Checks if an error response means the domain is already in the requested state
+///
+/// # Returns
+/// A Result containing the domain change, or the error if the request failed for another reason
+fn domain_change<E: HttpError>(
+    domain_name: String,
+    response: Result<String, E>,
+    unregistering: bool,
+    is_no_op: impl Fn(&ApiError) -> bool,
+) -> Result<ApplePayDomainChange, PaystackAPIError> {
+    let (message, already_present) = match response {
+        Ok(response) => {
+            let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::ApplePay(e.to_string()))?;
+            (parsed_response.message, unregistering)
+        }
+        Err(e) => match e.api_error().filter(|error| is_no_op(error)) {
+            Some(error) => (error.message.clone(), !unregistering),
+            None => return Err(PaystackAPIError::from_http(PaystackAPIError::ApplePay, e)),
+        },
+    };
+
+    Ok(ApplePayDomainChange {
+        domain_name,
+        already_present,
+        message,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::fake::{FakeClient, FakeError, FakeRequest};
+
+    /// Endpoints over a client serving the pages of domains, each with the cursor of the
+    /// next page. Cursors are sized to index the page they point to.
+    fn endpoints(
+        pages: Vec<(&'static str, Option<&'static str>)>,
+    ) -> (ApplePayEndpoints<FakeClient>, FakeClient) {
+        let http = FakeClient::new(move |request| match request.method {
+            "GET" => {
+                let page = request.param("next").map_or(0, str::len);
+                let (domain, next) = pages
+                    .get(page)
+                    .ok_or_else(|| FakeError::api(404, "no such page"))?;
+                let body = json!({
+                    "status": true,
+                    "message": "Apple Pay registered domains retrieved",
+                    "data": {"domainNames": [domain]},
+                    "meta": {"next": next, "previous": null}
+                });
+                Ok(body.to_string())
+            }
+            "POST" => Ok(
+                r#"{"status": true, "message": "Domain successfully registered on Apple Pay"}"#
+                    .to_string(),
+            ),
+            "DELETE" => Ok(
+                r#"{"status": true, "message": "Domain successfully unregistered on Apple Pay"}"#
+                    .to_string(),
+            ),
+            method => Err(FakeError::api(405, method)),
+        });
+        let endpoints = ApplePayEndpoints::new(Arc::new("key".to_string()), Arc::new(http.clone()));
+        (endpoints, http)
+    }
+
+    /// Summary of a request, e.g. `register "pay.example.com"` or `list first`
+    fn describe(request: &FakeRequest) -> String {
+        match request.method {
+            "GET" => format!("list {}", request.param("next").unwrap_or("first")),
+            "POST" => format!("register {}", request.body["domainName"]),
+            _ => format!("unregister {}", request.body["domainName"]),
+        }
+    }
+
+    #[tokio::test]
+    async fn register_and_unregister_send_a_single_request() {
+        let (endpoints, requests) = endpoints(vec![]);
+
+        let registered = endpoints
+            .register_domain("pay.example.com".to_string())
             .await
-            .map_err(|e| PaystackAPIError::ApplePay(e.to_string()))?;
+            .unwrap();
+        let unregistered = endpoints
+            .unregister_domain("pay.example.com".to_string())
+            .await
+            .unwrap();
 
-        let parsed_response = serde_json::from_str(&response)
-            .map_err(|e| PaystackAPIError::ApplePay(e.to_string()))?;
+        assert_eq!(
+            registered.message,
+            "Domain successfully registered on Apple Pay"
+        );
+        assert_eq!(
+            unregistered.message,
+            "Domain successfully unregistered on Apple Pay"
+        );
+        assert!(!registered.already_present);
+        assert!(unregistered.already_present);
+        assert_eq!(
+            requests.requests().iter().map(describe).collect::<Vec<_>>(),
+            vec![
+                r#"register "pay.example.com""#.to_string(),
+                r#"unregister "pay.example.com""#.to_string()
+            ]
+        );
+    }
 
-        Ok(parsed_response)
+    #[tokio::test]
+    async fn domain_changes_report_domains_already_in_place() {
+        let http = FakeClient::new(|request| match request.method {
+            "POST" => Err(FakeError::api(
+                400,
+                r#"{"status": false, "message": "Domain already registered"}"#,
+            )),
+            _ => Err(FakeError::api(
+                404,
+                r#"{"status": false, "message": "Domain not found"}"#,
+            )),
+        });
+        let endpoints = ApplePayEndpoints::new(Arc::new("key".to_string()), Arc::new(http));
+
+        let registered = endpoints
+            .register_domain("pay.example.com".to_string())
+            .await
+            .unwrap();
+        let unregistered = endpoints
+            .unregister_domain("pay.example.com".to_string())
+            .await
+            .unwrap();
+
+        assert!(registered.already_present);
+        assert_eq!(registered.message, "Domain already registered");
+        assert!(!unregistered.already_present);
+        assert_eq!(unregistered.domain_name, "pay.example.com");
+    }
+
+    #[tokio::test]
+    async fn other_domain_change_errors_are_returned() {
+        let http = FakeClient::new(|_| {
+            Err(FakeError::api(
+                401,
+                r#"{"status": false, "message": "Invalid key"}"#,
+            ))
+        });
+        let endpoints = ApplePayEndpoints::new(Arc::new("key".to_string()), Arc::new(http));
+
+        let registered = endpoints
+            .register_domain("pay.example.com".to_string())
+            .await;
+        let unregistered = endpoints
+            .unregister_domain("pay.example.com".to_string())
+            .await;
+
+        assert!(registered.unwrap_err().is_auth_error());
+        assert!(unregistered.unwrap_err().is_auth_error());
+    }
+
+    #[tokio::test]
+    async fn domain_lookup_goes_through_every_page() {
+        let (endpoints, requests) =
+            endpoints(vec![("example.com", Some("a")), ("pay.example.com", None)]);
+
+        assert!(endpoints
+            .is_domain_registered("PAY.example.com")
+            .await
+            .unwrap());
+        assert!(!endpoints
+            .is_domain_registered("new.example.com")
+            .await
+            .unwrap());
+        assert_eq!(requests.requests().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn domain_lookup_stops_when_a_cursor_repeats() {
+        let (endpoints, requests) = endpoints(vec![
+            ("example.com", Some("a")),
+            ("pay.example.com", Some("a")),
+        ]);
+
+        let registered = endpoints.is_domain_registered("new.example.com").await;
+
+        assert!(!registered.unwrap());
+        assert_eq!(
+            requests.requests().iter().map(describe).collect::<Vec<_>>(),
+            vec!["list first".to_string(), "list a".to_string()]
+        );
     }
 }
@@ -0,0 +1,244 @@
+//! Bulk Charges
+//! ============
+//! The Bulk Charges API allows you to create and manage multiple recurring payments from your customers.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    BulkChargeBatchData, BulkChargeData, BulkChargeItem, HttpClient, ListBulkChargeBatchesFilter,
+    ListBulkChargesFilter, PaystackAPIError, PaystackResult, Response,
+};
+use std::{marker::PhantomData, sync::Arc};
+
+/// A struct to hold all the functions of the bulk charges API endpoint
+#[derive(Debug, Clone)]
+pub struct BulkChargeEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the bulk charge route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> BulkChargeEndpoints<T> {
+    /// Creates a new BulkChargeEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new BulkChargeEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> BulkChargeEndpoints<T> {
+        BulkChargeEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new BulkChargeEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new BulkChargeEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> BulkChargeEndpoints<T> {
+        let base_url = format!("{base_url}/bulkcharge");
+        BulkChargeEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Send a list of authorizations to charge in a batch
+    ///
+    /// # Arguments
+    /// * `items` - The charges in the batch.
+    ///   Each item can be created from an `Authorization` with `BulkChargeItem::from_authorization`.
+    ///
+    /// # Returns
+    /// A Result containing the created batch or an error
+    pub async fn initiate_bulk_charge(
+        &self,
+        items: Vec<BulkChargeItem>,
+    ) -> PaystackResult<BulkChargeBatchData> {
+        let url = &self.base_url;
+        let body =
+            serde_json::to_value(items).map_err(|e| PaystackAPIError::BulkCharge(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::BulkCharge, e))?;
+
+        let parsed_response: Response<BulkChargeBatchData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::BulkCharge(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// List bulk charge batches created by the integration
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the batches returned.
+    ///   It should be created with the `ListBulkChargeBatchesFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of batches or an error
+    pub async fn list_bulk_charge_batches(
+        &self,
+        filter: Option<ListBulkChargeBatchesFilter>,
+    ) -> PaystackResult<Vec<BulkChargeBatchData>> {
+        let url = &self.base_url;
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(from) = filter.from {
+            query.push(("from", from));
+        }
+        if let Some(to) = filter.to {
+            query.push(("to", to));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::BulkCharge, e))?;
+
+        let parsed_response: Response<Vec<BulkChargeBatchData>> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::BulkCharge(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get a specific batch code. It also returns useful information on its progress
+    ///
+    /// # Arguments
+    /// * `id_or_code` - An ID or code for the batch whose details you want to retrieve
+    ///
+    /// # Returns
+    /// A Result containing the batch or an error
+    pub async fn fetch_bulk_charge_batch(
+        &self,
+        id_or_code: String,
+    ) -> PaystackResult<BulkChargeBatchData> {
+        let url = format!("{}/{}", self.base_url, id_or_code);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::BulkCharge, e))?;
+
+        let parsed_response: Response<BulkChargeBatchData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::BulkCharge(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Retrieve the charges associated with a specified batch code
+    ///
+    /// # Arguments
+    /// * `id_or_code` - An ID or code for the batch whose charges you want to retrieve
+    /// * `filter` - Optional set of parameters to filter the charges returned.
+    ///   It should be created with the `ListBulkChargesFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of charges in the batch or an error
+    pub async fn fetch_charges_in_batch(
+        &self,
+        id_or_code: String,
+        filter: Option<ListBulkChargesFilter>,
+    ) -> PaystackResult<Vec<BulkChargeData>> {
+        let url = format!("{}/{}/charges", self.base_url, id_or_code);
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(status) = filter.status {
+            query.push(("status", status.to_string()));
+        }
+        if let Some(from) = filter.from {
+            query.push(("from", from));
+        }
+        if let Some(to) = filter.to {
+            query.push(("to", to));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(&url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::BulkCharge, e))?;
+
+        let parsed_response: Response<Vec<BulkChargeData>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::BulkCharge(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Pause processing a batch
+    ///
+    /// # Arguments
+    /// * `batch_code` - The batch code for the bulk charge you want to pause
+    ///
+    /// # Returns
+    /// A Result containing a success message if the batch has been paused
+    pub async fn pause_bulk_charge_batch(
+        &self,
+        batch_code: String,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/pause/{}", self.base_url, batch_code);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::BulkCharge, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::BulkCharge(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Resume processing a batch
+    ///
+    /// # Arguments
+    /// * `batch_code` - The batch code for the bulk charge you want to resume
+    ///
+    /// # Returns
+    /// A Result containing a success message if the batch has been resumed
+    pub async fn resume_bulk_charge_batch(
+        &self,
+        batch_code: String,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/resume/{}", self.base_url, batch_code);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::BulkCharge, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::BulkCharge(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
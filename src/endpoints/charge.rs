@@ -0,0 +1,221 @@
+//! Charge
+//! ======
+//! The Charge API allows you to configure a payment channel of your choice when initiating a payment.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    DirectChargeRequest, DirectChargeResponseData, HttpClient, PaystackAPIError, PaystackResult,
+    Response, SubmitAddressRequest,
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+/// A struct to hold all the functions of the charge API endpoint
+#[derive(Debug, Clone)]
+pub struct ChargeEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the charge route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> ChargeEndpoints<T> {
+    /// Creates a new ChargeEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new ChargeEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> ChargeEndpoints<T> {
+        ChargeEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new ChargeEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new ChargeEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> ChargeEndpoints<T> {
+        let base_url = format!("{base_url}/charge");
+        ChargeEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Initiate a payment by integrating the payment channel of your choice.
+    /// The `status` of the response indicates the next step of the charge.
+    ///
+    /// # Arguments
+    /// * `charge_request` - The request data to create the charge.
+    ///   Should be created with a `DirectChargeRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the charge response data or an error
+    pub async fn create_charge(
+        &self,
+        charge_request: DirectChargeRequest,
+    ) -> PaystackResult<DirectChargeResponseData> {
+        let url = &self.base_url;
+        let body = serde_json::to_value(charge_request)
+            .map_err(|e| PaystackAPIError::Charge(e.to_string()))?;
+
+        self.submit(url, &body).await
+    }
+
+    /// Submit PIN to continue a charge
+    ///
+    /// # Arguments
+    /// * `pin` - PIN submitted by user
+    /// * `reference` - Reference for the ongoing transaction
+    ///
+    /// # Returns
+    /// A Result containing the charge response data or an error
+    pub async fn submit_pin(
+        &self,
+        pin: String,
+        reference: String,
+    ) -> PaystackResult<DirectChargeResponseData> {
+        let url = format!("{}/submit_pin", self.base_url);
+        let body = json!({
+            "pin": pin,
+            "reference": reference
+        });
+
+        self.submit(&url, &body).await
+    }
+
+    /// Submit OTP to complete a charge
+    ///
+    /// # Arguments
+    /// * `otp` - OTP submitted by user
+    /// * `reference` - Reference for the ongoing transaction
+    ///
+    /// # Returns
+    /// A Result containing the charge response data or an error
+    pub async fn submit_otp(
+        &self,
+        otp: String,
+        reference: String,
+    ) -> PaystackResult<DirectChargeResponseData> {
+        let url = format!("{}/submit_otp", self.base_url);
+        let body = json!({
+            "otp": otp,
+            "reference": reference
+        });
+
+        self.submit(&url, &body).await
+    }
+
+    /// Submit phone number when requested
+    ///
+    /// # Arguments
+    /// * `phone` - Phone number submitted by user
+    /// * `reference` - Reference for the ongoing transaction
+    ///
+    /// # Returns
+    /// A Result containing the charge response data or an error
+    pub async fn submit_phone(
+        &self,
+        phone: String,
+        reference: String,
+    ) -> PaystackResult<DirectChargeResponseData> {
+        let url = format!("{}/submit_phone", self.base_url);
+        let body = json!({
+            "phone": phone,
+            "reference": reference
+        });
+
+        self.submit(&url, &body).await
+    }
+
+    /// Submit birthday when requested
+    ///
+    /// # Arguments
+    /// * `birthday` - Birthday submitted by user e.g. `1961-09-21`
+    /// * `reference` - Reference for the ongoing transaction
+    ///
+    /// # Returns
+    /// A Result containing the charge response data or an error
+    pub async fn submit_birthday(
+        &self,
+        birthday: String,
+        reference: String,
+    ) -> PaystackResult<DirectChargeResponseData> {
+        let url = format!("{}/submit_birthday", self.base_url);
+        let body = json!({
+            "birthday": birthday,
+            "reference": reference
+        });
+
+        self.submit(&url, &body).await
+    }
+
+    /// Submit address to continue a charge
+    ///
+    /// # Arguments
+    /// * `address_request` - The address submitted by the user.
+    ///   Should be created with a `SubmitAddressRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the charge response data or an error
+    pub async fn submit_address(
+        &self,
+        address_request: SubmitAddressRequest,
+    ) -> PaystackResult<DirectChargeResponseData> {
+        let url = format!("{}/submit_address", self.base_url);
+        let body = serde_json::to_value(address_request)
+            .map_err(|e| PaystackAPIError::Charge(e.to_string()))?;
+
+        self.submit(&url, &body).await
+    }
+
+    /// Check the status of a pending charge.
+    /// Paystack recommends waiting at least 10 seconds before checking.
+    ///
+    /// # Arguments
+    /// * `reference` - The reference to check
+    ///
+    /// # Returns
+    /// A Result containing the charge response data or an error
+    pub async fn check_pending_charge(
+        &self,
+        reference: String,
+    ) -> PaystackResult<DirectChargeResponseData> {
+        let url = format!("{}/{}", self.base_url, reference);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Charge, e))?;
+
+        let parsed_response: Response<DirectChargeResponseData> =
+            serde_json::from_str(&response).map_err(|e| PaystackAPIError::Charge(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Sends a charge step to the given URL and parses the charge response
+    async fn submit(&self, url: &str, body: &Value) -> PaystackResult<DirectChargeResponseData> {
+        let response = self
+            .http
+            .post(url, &self.key, body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Charge, e))?;
+
+        let parsed_response: Response<DirectChargeResponseData> =
+            serde_json::from_str(&response).map_err(|e| PaystackAPIError::Charge(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
@@ -4,8 +4,10 @@
 
 use super::PAYSTACK_BASE_URL;
 use crate::{
-    CreateCustomerRequest, CustomerResponseData, HttpClient, PaystackAPIError, PaystackResult,
-    Response, RiskAction, UpdateCustomerRequest, ValidateCustomerRequest,
+    CreateCustomerRequest, CustomerResponseData, HttpClient, InitializeAuthorizationData,
+    InitializeAuthorizationRequest, InitializeDirectDebitRequest, ListCustomersFilter,
+    MandateAuthorizationData, PaystackAPIError, PaystackResult, Response, RiskAction,
+    UpdateCustomerRequest, ValidateCustomerRequest, VerifyAuthorizationData,
 };
 use serde_json::json;
 use std::{marker::PhantomData, sync::Arc};
@@ -31,7 +33,20 @@ impl<T: HttpClient + Default> CustomersEndpoints<T> {
     /// # Returns
     /// A new CustomersEndpoints instance
     pub fn new(key: Arc<String>, http: Arc<T>) -> CustomersEndpoints<T> {
-        let base_url = format!("{PAYSTACK_BASE_URL}/customer");
+        CustomersEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new CustomersEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new CustomersEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> CustomersEndpoints<T> {
+        let base_url = format!("{base_url}/customer");
         CustomersEndpoints {
             key: key.to_string(),
             base_url,
@@ -59,7 +74,7 @@ impl<T: HttpClient + Default> CustomersEndpoints<T> {
             .http
             .post(url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Customer, e))?;
 
         let parsed_response: Response<CustomerResponseData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
@@ -79,18 +94,50 @@ impl<T: HttpClient + Default> CustomersEndpoints<T> {
         &self,
         per_page: Option<u8>,
         page: Option<u8>,
+    ) -> PaystackResult<Vec<CustomerResponseData>> {
+        let filter = ListCustomersFilter {
+            per_page: per_page.map(u32::from),
+            page: page.map(u32::from),
+            ..Default::default()
+        };
+
+        self.list_customers_with_filter(Some(filter)).await
+    }
+
+    /// Lists customers available on your integration, filtered by creation date
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the customers returned.
+    ///   It should be created with the `ListCustomersFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of customer response data or an error
+    pub async fn list_customers_with_filter(
+        &self,
+        filter: Option<ListCustomersFilter>,
     ) -> PaystackResult<Vec<CustomerResponseData>> {
         let url = &self.base_url;
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
 
-        let per_page = per_page.unwrap_or(50).to_string();
-        let page = page.unwrap_or(1).to_string();
-        let query = vec![("perPage", per_page.as_str()), ("page", page.as_str())];
+        if let Some(from) = filter.from {
+            query.push(("from", from));
+        }
+        if let Some(to) = filter.to {
+            query.push(("to", to));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
         let response = self
             .http
             .get(url, &self.key, Some(&query))
             .await
-            .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Customer, e))?;
 
         let parsed_response: Response<Vec<CustomerResponseData>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
@@ -115,7 +162,7 @@ impl<T: HttpClient + Default> CustomersEndpoints<T> {
             .http
             .get(&url, &self.key, None)
             .await
-            .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Customer, e))?;
 
         let parsed_response: Response<CustomerResponseData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
@@ -145,7 +192,7 @@ impl<T: HttpClient + Default> CustomersEndpoints<T> {
             .http
             .put(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Customer, e))?;
 
         let parsed_response: Response<CustomerResponseData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
@@ -175,7 +222,7 @@ impl<T: HttpClient + Default> CustomersEndpoints<T> {
             .http
             .post(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Customer, e))?;
 
         let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
@@ -206,7 +253,7 @@ impl<T: HttpClient + Default> CustomersEndpoints<T> {
             .http
             .post(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Customer, e))?;
 
         let parsed_response: Response<CustomerResponseData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
@@ -234,11 +281,158 @@ impl<T: HttpClient + Default> CustomersEndpoints<T> {
             .http
             .post(&url, &self.key, &body)
             .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Customer, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Initiates a request for a customer to authorize a direct debit mandate
+    ///
+    /// # Arguments
+    /// * `authorization_request` - The request data to initialize the authorization.
+    ///   Should be created with the `InitializeAuthorizationRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the URL to redirect the customer to, or an error
+    pub async fn initialize_authorization(
+        &self,
+        authorization_request: InitializeAuthorizationRequest,
+    ) -> PaystackResult<InitializeAuthorizationData> {
+        let url = format!("{}/authorization/initialize", self.base_url);
+        let body = serde_json::to_value(authorization_request)
             .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
 
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Customer, e))?;
+
+        let parsed_response: Response<InitializeAuthorizationData> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Checks the status of an authorization request
+    ///
+    /// # Arguments
+    /// * `reference` - The reference returned when the authorization was initialized
+    ///
+    /// # Returns
+    /// A Result containing the verified authorization or an error
+    pub async fn verify_authorization(
+        &self,
+        reference: String,
+    ) -> PaystackResult<VerifyAuthorizationData> {
+        let url = format!("{}/authorization/verify/{}", self.base_url, reference);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Customer, e))?;
+
+        let parsed_response: Response<VerifyAuthorizationData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Initializes a direct debit mandate on an existing customer
+    ///
+    /// # Arguments
+    /// * `customer_id` - The ID of the customer
+    /// * `direct_debit_request` - The bank account and address of the customer.
+    ///   Should be created with the `InitializeDirectDebitRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the URL to redirect the customer to, or an error
+    pub async fn initialize_direct_debit(
+        &self,
+        customer_id: u64,
+        direct_debit_request: InitializeDirectDebitRequest,
+    ) -> PaystackResult<InitializeAuthorizationData> {
+        let url = format!("{}/{}/initialize-direct-debit", self.base_url, customer_id);
+        let body = serde_json::to_value(direct_debit_request)
+            .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Customer, e))?;
+
+        let parsed_response: Response<InitializeAuthorizationData> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Triggers an activation charge on an inactive direct debit mandate of a customer
+    ///
+    /// # Arguments
+    /// * `customer_id` - The ID of the customer
+    /// * `authorization_id` - The ID of the authorization to activate
+    ///
+    /// # Returns
+    /// A Result containing a success message if the activation charge has been queued
+    pub async fn direct_debit_activation_charge(
+        &self,
+        customer_id: u64,
+        authorization_id: u64,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!(
+            "{}/{}/directdebit-activation-charge",
+            self.base_url, customer_id
+        );
+        let body = json!({
+            "authorization_id": authorization_id
+        });
+
+        let response = self
+            .http
+            .put(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Customer, e))?;
+
         let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
 
         Ok(parsed_response)
     }
+
+    /// Gets the list of direct debit mandates associated with a customer
+    ///
+    /// # Arguments
+    /// * `customer_id` - The ID of the customer
+    ///
+    /// # Returns
+    /// A Result containing a vector of mandate authorizations or an error
+    pub async fn fetch_mandate_authorizations(
+        &self,
+        customer_id: u64,
+    ) -> PaystackResult<Vec<MandateAuthorizationData>> {
+        let url = format!(
+            "{}/{}/directdebit-mandate-authorizations",
+            self.base_url, customer_id
+        );
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Customer, e))?;
+
+        let parsed_response: Response<Vec<MandateAuthorizationData>> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::Customer(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
 }
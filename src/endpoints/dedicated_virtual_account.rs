@@ -29,7 +29,24 @@ impl<T: HttpClient + Default> DedicatedVirtualAccountEndpoints<T> {
     /// # Returns
     /// A new DedicatedVirtualAccountEndpoints instance
     pub fn new(key: Arc<String>, http: Arc<T>) -> DedicatedVirtualAccountEndpoints<T> {
-        let base_url = format!("{PAYSTACK_BASE_URL}/dedicated_account");
+        DedicatedVirtualAccountEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new DedicatedVirtualAccountEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new DedicatedVirtualAccountEndpoints instance
+    pub fn with_base_url(
+        key: Arc<String>,
+        http: Arc<T>,
+        base_url: &str,
+    ) -> DedicatedVirtualAccountEndpoints<T> {
+        let base_url = format!("{base_url}/dedicated_account");
         DedicatedVirtualAccountEndpoints {
             key: key.to_string(),
             base_url,
@@ -53,11 +70,9 @@ impl<T: HttpClient + Default> DedicatedVirtualAccountEndpoints<T> {
         let body = serde_json::to_value(create_dedicated_virtual_account_request)
             .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
 
-        let response = self
-            .http
-            .post(url, &self.key, &body)
-            .await
-            .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
+        let response = self.http.post(url, &self.key, &body).await.map_err(|e| {
+            PaystackAPIError::from_http(PaystackAPIError::DedicatedVirtualAccount, e)
+        })?;
 
         let parsed_response: Response<DedicatedVirtualAccountResponseData> =
             serde_json::from_str(&response)
@@ -82,11 +97,9 @@ impl<T: HttpClient + Default> DedicatedVirtualAccountEndpoints<T> {
         let body = serde_json::to_value(assign_dedicated_virtual_account_request)
             .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
 
-        let response = self
-            .http
-            .post(url, &self.key, &body)
-            .await
-            .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
+        let response = self.http.post(url, &self.key, &body).await.map_err(|e| {
+            PaystackAPIError::from_http(PaystackAPIError::DedicatedVirtualAccount, e)
+        })?;
 
         let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
@@ -133,7 +146,9 @@ impl<T: HttpClient + Default> DedicatedVirtualAccountEndpoints<T> {
             .http
             .get(url, &self.key, Some(&query))
             .await
-            .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
+            .map_err(|e| {
+                PaystackAPIError::from_http(PaystackAPIError::DedicatedVirtualAccount, e)
+            })?;
 
         let parsed_response: Response<Vec<DedicatedVirtualAccountResponseData>> =
             serde_json::from_str(&response)
@@ -155,11 +170,9 @@ impl<T: HttpClient + Default> DedicatedVirtualAccountEndpoints<T> {
     ) -> PaystackResult<DedicatedVirtualAccountResponseData> {
         let url = format!("{}/{}", self.base_url, dedicated_account_id);
 
-        let response = self
-            .http
-            .get(&url, &self.key, None)
-            .await
-            .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
+        let response = self.http.get(&url, &self.key, None).await.map_err(|e| {
+            PaystackAPIError::from_http(PaystackAPIError::DedicatedVirtualAccount, e)
+        })?;
 
         let parsed_response: Response<DedicatedVirtualAccountResponseData> =
             serde_json::from_str(&response)
@@ -199,7 +212,9 @@ impl<T: HttpClient + Default> DedicatedVirtualAccountEndpoints<T> {
             .http
             .get(&url, &self.key, Some(&query))
             .await
-            .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
+            .map_err(|e| {
+                PaystackAPIError::from_http(PaystackAPIError::DedicatedVirtualAccount, e)
+            })?;
 
         let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
@@ -225,7 +240,9 @@ impl<T: HttpClient + Default> DedicatedVirtualAccountEndpoints<T> {
             .http
             .delete(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
+            .map_err(|e| {
+                PaystackAPIError::from_http(PaystackAPIError::DedicatedVirtualAccount, e)
+            })?;
 
         let parsed_response: Response<DedicatedVirtualAccountResponseData> =
             serde_json::from_str(&response)
@@ -250,11 +267,9 @@ impl<T: HttpClient + Default> DedicatedVirtualAccountEndpoints<T> {
         let body = serde_json::to_value(split_dedocated_account_transaction_request)
             .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
 
-        let response = self
-            .http
-            .post(url, &self.key, &body)
-            .await
-            .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
+        let response = self.http.post(url, &self.key, &body).await.map_err(|e| {
+            PaystackAPIError::from_http(PaystackAPIError::DedicatedVirtualAccount, e)
+        })?;
 
         let parsed_response: Response<DedicatedVirtualAccountResponseData> =
             serde_json::from_str(&response)
@@ -279,11 +294,9 @@ impl<T: HttpClient + Default> DedicatedVirtualAccountEndpoints<T> {
             "account_number": account_number
         });
 
-        let response = self
-            .http
-            .delete(url, &self.key, &body)
-            .await
-            .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
+        let response = self.http.delete(url, &self.key, &body).await.map_err(|e| {
+            PaystackAPIError::from_http(PaystackAPIError::DedicatedVirtualAccount, e)
+        })?;
 
         let parsed_response: Response<DedicatedVirtualAccountResponseData> =
             serde_json::from_str(&response)
@@ -302,11 +315,9 @@ impl<T: HttpClient + Default> DedicatedVirtualAccountEndpoints<T> {
     pub async fn fetch_bank_providers(&self) -> PaystackResult<Vec<BankProviderData>> {
         let url = format!("{}/available_providers", self.base_url);
 
-        let response = self
-            .http
-            .get(&url, &self.key, None)
-            .await
-            .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
+        let response = self.http.get(&url, &self.key, None).await.map_err(|e| {
+            PaystackAPIError::from_http(PaystackAPIError::DedicatedVirtualAccount, e)
+        })?;
 
         let parsed_response: Response<Vec<BankProviderData>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::DedicatedVirtualAccount(e.to_string()))?;
@@ -0,0 +1,128 @@
+//! Direct Debit
+//! ============
+//! The Direct Debit API allows you to manage the authorization on your customer's bank accounts.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    HttpClient, ListMandateAuthorizationsFilter, MandateAuthorizationData, PaystackAPIError,
+    PaystackResult, Response,
+};
+use serde_json::json;
+use std::{marker::PhantomData, sync::Arc};
+
+/// A struct to hold all the functions of the direct debit API endpoint
+#[derive(Debug, Clone)]
+pub struct DirectDebitEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the direct debit route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> DirectDebitEndpoints<T> {
+    /// Creates a new DirectDebitEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new DirectDebitEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> DirectDebitEndpoints<T> {
+        DirectDebitEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new DirectDebitEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new DirectDebitEndpoints instance
+    pub fn with_base_url(
+        key: Arc<String>,
+        http: Arc<T>,
+        base_url: &str,
+    ) -> DirectDebitEndpoints<T> {
+        let base_url = format!("{base_url}/directdebit");
+        DirectDebitEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Trigger an activation charge on pending mandates on behalf of your customers
+    ///
+    /// # Arguments
+    /// * `customer_ids` - IDs of the customers to trigger the activation charge for
+    ///
+    /// # Returns
+    /// A Result containing a success message if the activation charges have been queued
+    pub async fn trigger_activation_charge(
+        &self,
+        customer_ids: Vec<u64>,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/activation-charge", self.base_url);
+        let body = json!({
+            "customer_ids": customer_ids
+        });
+
+        let response = self
+            .http
+            .put(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::DirectDebit, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::DirectDebit(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get the list of direct debit mandates on your integration
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the mandates returned.
+    ///   It should be created with the `ListMandateAuthorizationsFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of mandate authorizations or an error.
+    /// The cursor of the next page is in the `meta.next` of the response.
+    pub async fn list_mandate_authorizations(
+        &self,
+        filter: Option<ListMandateAuthorizationsFilter>,
+    ) -> PaystackResult<Vec<MandateAuthorizationData>> {
+        let url = format!("{}/mandate-authorizations", self.base_url);
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let mut query = vec![("per_page", per_page)];
+
+        if let Some(cursor) = filter.cursor {
+            query.push(("cursor", cursor));
+        }
+        if let Some(status) = filter.status {
+            query.push(("status", status.to_string()));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(&url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::DirectDebit, e))?;
+
+        let parsed_response: Response<Vec<MandateAuthorizationData>> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::DirectDebit(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
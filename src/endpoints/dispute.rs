@@ -0,0 +1,304 @@
+//! Disputes
+//! ========
+//! The Disputes API allows you to manage transaction disputes on your integration.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    DisputeEvidenceData, DisputeEvidenceRequest, DisputeResponseData, DisputeUploadUrlData,
+    ExportDisputeData, HttpClient, ListDisputesFilter, PaystackAPIError, PaystackResult,
+    ResolveDisputeRequest, Response, UpdateDisputeRequest,
+};
+use std::sync::Arc;
+
+/// A struct to hold all the functions of the disputes API endpoint
+#[derive(Debug, Clone)]
+pub struct DisputeEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the dispute route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> DisputeEndpoints<T> {
+    /// Creates a new DisputeEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new DisputeEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> DisputeEndpoints<T> {
+        DisputeEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new DisputeEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new DisputeEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> DisputeEndpoints<T> {
+        let base_url = format!("{base_url}/dispute");
+        DisputeEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// List disputes filed against you
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the disputes returned.
+    ///   It should be created with the `ListDisputesFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of dispute response data or an error
+    pub async fn list_disputes(
+        &self,
+        filter: Option<ListDisputesFilter>,
+    ) -> PaystackResult<Vec<DisputeResponseData>> {
+        let url = &self.base_url;
+        let query = build_query(filter);
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Dispute, e))?;
+
+        let parsed_response: Response<Vec<DisputeResponseData>> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::Dispute(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get more details about a dispute
+    ///
+    /// # Arguments
+    /// * `dispute_id` - The dispute ID you want to fetch
+    ///
+    /// # Returns
+    /// A Result containing the dispute response data or an error
+    pub async fn fetch_dispute(&self, dispute_id: u64) -> PaystackResult<DisputeResponseData> {
+        let url = format!("{}/{}", self.base_url, dispute_id);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Dispute, e))?;
+
+        let parsed_response: Response<DisputeResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Dispute(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Retrieve disputes for a particular transaction
+    ///
+    /// # Arguments
+    /// * `transaction_id` - The transaction ID you want to fetch
+    ///
+    /// # Returns
+    /// A Result containing the dispute response data or an error
+    pub async fn list_transaction_disputes(
+        &self,
+        transaction_id: u64,
+    ) -> PaystackResult<DisputeResponseData> {
+        let url = format!("{}/transaction/{}", self.base_url, transaction_id);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Dispute, e))?;
+
+        let parsed_response: Response<DisputeResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Dispute(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Update details of a dispute on your integration
+    ///
+    /// # Arguments
+    /// * `dispute_id` - Dispute ID
+    /// * `update_request` - The request data to update the dispute with.
+    ///   Should be created with the `UpdateDisputeRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the updated dispute response data or an error
+    pub async fn update_dispute(
+        &self,
+        dispute_id: u64,
+        update_request: UpdateDisputeRequest,
+    ) -> PaystackResult<DisputeResponseData> {
+        let url = format!("{}/{}", self.base_url, dispute_id);
+        let body = serde_json::to_value(update_request)
+            .map_err(|e| PaystackAPIError::Dispute(e.to_string()))?;
+
+        let response = self
+            .http
+            .put(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Dispute, e))?;
+
+        let parsed_response: Response<DisputeResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Dispute(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Provide evidence for a dispute
+    ///
+    /// # Arguments
+    /// * `dispute_id` - Dispute ID
+    /// * `evidence_request` - The evidence to add to the dispute.
+    ///   Should be created with the `DisputeEvidenceRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the evidence data or an error
+    pub async fn add_evidence(
+        &self,
+        dispute_id: u64,
+        evidence_request: DisputeEvidenceRequest,
+    ) -> PaystackResult<DisputeEvidenceData> {
+        let url = format!("{}/{}/evidence", self.base_url, dispute_id);
+        let body = serde_json::to_value(evidence_request)
+            .map_err(|e| PaystackAPIError::Dispute(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Dispute, e))?;
+
+        let parsed_response: Response<DisputeEvidenceData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Dispute(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get URL to upload a dispute evidence.
+    ///
+    /// # Arguments
+    /// * `dispute_id` - Dispute ID
+    /// * `upload_filename` - The file name, with its extension, that you want to upload e.g `filename.pdf`
+    ///
+    /// # Returns
+    /// A Result containing the upload URL data or an error
+    pub async fn get_upload_url(
+        &self,
+        dispute_id: u64,
+        upload_filename: String,
+    ) -> PaystackResult<DisputeUploadUrlData> {
+        let url = format!("{}/{}/upload_url", self.base_url, dispute_id);
+        let query = vec![("upload_filename", upload_filename.as_str())];
+
+        let response = self
+            .http
+            .get(&url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Dispute, e))?;
+
+        let parsed_response: Response<DisputeUploadUrlData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Dispute(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Resolve a dispute on your integration
+    ///
+    /// # Arguments
+    /// * `dispute_id` - Dispute ID
+    /// * `resolve_request` - The request data to resolve the dispute with.
+    ///   Should be created with the `ResolveDisputeRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the resolved dispute response data or an error
+    pub async fn resolve_dispute(
+        &self,
+        dispute_id: u64,
+        resolve_request: ResolveDisputeRequest,
+    ) -> PaystackResult<DisputeResponseData> {
+        let url = format!("{}/{}/resolve", self.base_url, dispute_id);
+        let body = serde_json::to_value(resolve_request)
+            .map_err(|e| PaystackAPIError::Dispute(e.to_string()))?;
+
+        let response = self
+            .http
+            .put(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Dispute, e))?;
+
+        let parsed_response: Response<DisputeResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Dispute(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Export disputes available on your integration
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the disputes exported.
+    ///   It should be created with the `ListDisputesFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the export dispute data or an error
+    pub async fn export_disputes(
+        &self,
+        filter: Option<ListDisputesFilter>,
+    ) -> PaystackResult<ExportDisputeData> {
+        let url = format!("{}/export", self.base_url);
+        let query = build_query(filter);
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(&url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Dispute, e))?;
+
+        let parsed_response: Response<ExportDisputeData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Dispute(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
+
+/// Builds the query shared by the list and export disputes endpoints
+fn build_query(filter: Option<ListDisputesFilter>) -> Vec<(&'static str, String)> {
+    let filter = filter.unwrap_or_default();
+
+    let per_page = filter.per_page.unwrap_or(50).to_string();
+    let page = filter.page.unwrap_or(1).to_string();
+    let mut query = vec![("perPage", per_page), ("page", page)];
+
+    if let Some(from) = filter.from {
+        query.push(("from", from));
+    }
+    if let Some(to) = filter.to {
+        query.push(("to", to));
+    }
+    if let Some(transaction) = filter.transaction {
+        query.push(("transaction", transaction));
+    }
+    if let Some(status) = filter.status {
+        query.push(("status", status.to_string()));
+    }
+
+    query
+}
@@ -0,0 +1,102 @@
+//! Integration
+//! ===========
+//! The Integration API allows you to manage some settings on your integration.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{HttpClient, PaymentSessionTimeoutData, PaystackAPIError, PaystackResult, Response};
+use serde_json::json;
+use std::sync::Arc;
+
+/// A struct to hold all the functions of the integration API endpoint
+#[derive(Debug, Clone)]
+pub struct IntegrationEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the integration route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> IntegrationEndpoints<T> {
+    /// Creates a new IntegrationEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new IntegrationEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> IntegrationEndpoints<T> {
+        IntegrationEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new IntegrationEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new IntegrationEndpoints instance
+    pub fn with_base_url(
+        key: Arc<String>,
+        http: Arc<T>,
+        base_url: &str,
+    ) -> IntegrationEndpoints<T> {
+        let base_url = format!("{base_url}/integration");
+        IntegrationEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Fetch the payment session timeout on your integration
+    ///
+    /// # Returns
+    /// A Result containing the payment session timeout or an error
+    pub async fn fetch_payment_session_timeout(&self) -> PaystackResult<PaymentSessionTimeoutData> {
+        let url = format!("{}/payment_session_timeout", self.base_url);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Integration, e))?;
+
+        let parsed_response: Response<PaymentSessionTimeoutData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Integration(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Update the payment session timeout on your integration
+    ///
+    /// # Arguments
+    /// * `timeout` - Time before stopping session (in seconds). Set to `0` to cancel session timeouts
+    ///
+    /// # Returns
+    /// A Result containing the updated payment session timeout or an error
+    pub async fn update_payment_session_timeout(
+        &self,
+        timeout: u32,
+    ) -> PaystackResult<PaymentSessionTimeoutData> {
+        let url = format!("{}/payment_session_timeout", self.base_url);
+        let body = json!({
+            "timeout": timeout
+        });
+
+        let response = self
+            .http
+            .put(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Integration, e))?;
+
+        let parsed_response: Response<PaymentSessionTimeoutData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Integration(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
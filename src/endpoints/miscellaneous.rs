@@ -0,0 +1,290 @@
+//! Miscellaneous
+//! =============
+//! The Miscellaneous API are supporting APIs that can be used to provide more details to other APIs.
+//!
+//! The responses of these endpoints rarely change, so the endpoints can optionally cache
+//! them in memory for a fixed duration. See `MiscellaneousEndpoints::with_cache`, or
+//! `PaystackClientBuilder::misc_cache_ttl` to enable it when building a client.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    BankData, CountryData, HttpClient, ListBanksFilter, PaystackAPIError, PaystackResult, Response,
+    StateData,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::Instant;
+
+/// A struct to hold all the functions of the miscellaneous API endpoint
+#[derive(Debug, Clone)]
+pub struct MiscellaneousEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the miscellaneous routes
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+    /// Optional cache of responses, shared between clones of the endpoints
+    cache: Option<Arc<ResponseCache>>,
+}
+
+/// Time-bounded in-memory cache of raw responses, keyed by URL and query
+#[derive(Debug)]
+struct ResponseCache {
+    /// How long a response stays valid
+    ttl: Duration,
+    /// Cached responses and the time they were stored
+    entries: Mutex<HashMap<String, (Instant, String)>>,
+}
+
+impl<T: HttpClient + Default> MiscellaneousEndpoints<T> {
+    /// Creates a new MiscellaneousEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new MiscellaneousEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> MiscellaneousEndpoints<T> {
+        MiscellaneousEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new MiscellaneousEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new MiscellaneousEndpoints instance
+    pub fn with_base_url(
+        key: Arc<String>,
+        http: Arc<T>,
+        base_url: &str,
+    ) -> MiscellaneousEndpoints<T> {
+        let base_url = base_url.to_string();
+        MiscellaneousEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+            cache: None,
+        }
+    }
+
+    /// Enables caching of successful responses for the given duration.
+    /// Repeated lookups within `ttl` are served from memory instead of the API,
+    /// and expired responses are dropped when a new response is cached.
+    ///
+    /// # Arguments
+    /// * `ttl` - How long a cached response stays valid
+    ///
+    /// # Returns
+    /// The MiscellaneousEndpoints instance with caching enabled
+    pub fn with_cache(mut self, ttl: Duration) -> MiscellaneousEndpoints<T> {
+        self.cache = Some(Arc::new(ResponseCache {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }));
+        self
+    }
+
+    /// Removes all cached responses. Does nothing if caching is not enabled.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache
+                .entries
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clear();
+        }
+    }
+
+    /// Get a list of all supported banks and their properties
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the banks returned.
+    ///   It should be created with the `ListBanksFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of bank data or an error
+    pub async fn list_banks(
+        &self,
+        filter: Option<ListBanksFilter>,
+    ) -> PaystackResult<Vec<BankData>> {
+        let url = format!("{}/bank", self.base_url);
+        let filter = filter.unwrap_or_default();
+
+        let mut query = vec![];
+        if let Some(country) = filter.country {
+            query.push(("country", country));
+        }
+        if let Some(currency) = filter.currency {
+            query.push(("currency", currency.to_string()));
+        }
+        if let Some(bank_type) = filter.bank_type {
+            query.push(("type", bank_type));
+        }
+        if let Some(pay_with_bank_transfer) = filter.pay_with_bank_transfer {
+            query.push(("pay_with_bank_transfer", pay_with_bank_transfer.to_string()));
+        }
+        if let Some(pay_with_bank) = filter.pay_with_bank {
+            query.push(("pay_with_bank", pay_with_bank.to_string()));
+        }
+        if let Some(per_page) = filter.per_page {
+            query.push(("perPage", per_page.to_string()));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self.get(&url, &query).await?;
+
+        let parsed_response: Response<Vec<BankData>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Miscellaneous(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Gets a list of countries that Paystack currently supports
+    ///
+    /// # Returns
+    /// A Result containing a vector of country data or an error
+    pub async fn list_countries(&self) -> PaystackResult<Vec<CountryData>> {
+        let url = format!("{}/country", self.base_url);
+
+        let response = self.get(&url, &[]).await?;
+
+        let parsed_response: Response<Vec<CountryData>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Miscellaneous(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get a list of states for a country for address verification
+    ///
+    /// # Arguments
+    /// * `country` - The country code of the states to list. It is gotten after the charge request.
+    ///
+    /// # Returns
+    /// A Result containing a vector of state data or an error
+    pub async fn list_states(&self, country: String) -> PaystackResult<Vec<StateData>> {
+        let url = format!("{}/address_verification/states", self.base_url);
+        let query = vec![("country", country.as_str())];
+
+        let response = self.get(&url, &query).await?;
+
+        let parsed_response: Response<Vec<StateData>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Miscellaneous(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Sends a GET request, serving it from the cache when caching is enabled
+    async fn get(&self, url: &str, query: &[(&str, &str)]) -> Result<String, PaystackAPIError> {
+        let cache_key = format!("{url}?{query:?}");
+
+        if let Some(cache) = &self.cache {
+            let entries = cache.entries.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some((stored_at, response)) = entries.get(&cache_key) {
+                if stored_at.elapsed() < cache.ttl {
+                    return Ok(response.clone());
+                }
+            }
+        }
+
+        let query = query.to_vec();
+        let query = if query.is_empty() { None } else { Some(&query) };
+        let response = self
+            .http
+            .get(url, &self.key, query)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Miscellaneous, e))?;
+
+        if let Some(cache) = &self.cache {
+            let mut entries = cache.entries.lock().unwrap_or_else(|e| e.into_inner());
+            entries.retain(|_, (stored_at, _)| stored_at.elapsed() < cache.ttl);
+            entries.insert(cache_key, (Instant::now(), response.clone()));
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::fake::FakeClient;
+
+    fn banks_client() -> FakeClient {
+        FakeClient::ok(r#"{"status": true, "message": "Banks retrieved", "data": []}"#)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cached_responses_are_reused_until_they_expire() {
+        let http = banks_client();
+        let endpoints =
+            MiscellaneousEndpoints::new(Arc::new("key".to_string()), Arc::new(http.clone()))
+                .with_cache(Duration::from_secs(60));
+
+        endpoints.list_banks(None).await.unwrap();
+        tokio::time::advance(Duration::from_secs(59)).await;
+        endpoints.list_banks(None).await.unwrap();
+        assert_eq!(http.requests().len(), 1);
+
+        tokio::time::advance(Duration::from_secs(1)).await;
+        endpoints.list_banks(None).await.unwrap();
+        assert_eq!(http.requests().len(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn expired_responses_are_evicted() {
+        let endpoints =
+            MiscellaneousEndpoints::new(Arc::new("key".to_string()), Arc::new(banks_client()))
+                .with_cache(Duration::from_secs(60));
+        let cached = || {
+            endpoints
+                .cache
+                .as_ref()
+                .unwrap()
+                .entries
+                .lock()
+                .unwrap()
+                .len()
+        };
+
+        endpoints.list_banks(None).await.unwrap();
+        tokio::time::advance(Duration::from_secs(60)).await;
+        endpoints.list_countries().await.unwrap();
+
+        assert_eq!(cached(), 1);
+    }
+
+    #[tokio::test]
+    async fn cleared_responses_are_fetched_again() {
+        let http = banks_client();
+        let endpoints =
+            MiscellaneousEndpoints::new(Arc::new("key".to_string()), Arc::new(http.clone()))
+                .with_cache(Duration::from_secs(60));
+
+        endpoints.list_banks(None).await.unwrap();
+        endpoints.clear_cache();
+        endpoints.list_banks(None).await.unwrap();
+        assert_eq!(http.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn responses_are_not_cached_by_default() {
+        let http = banks_client();
+        let endpoints =
+            MiscellaneousEndpoints::new(Arc::new("key".to_string()), Arc::new(http.clone()));
+
+        endpoints.list_countries().await.unwrap();
+        endpoints.list_countries().await.unwrap();
+        assert_eq!(http.requests().len(), 2);
+    }
+}
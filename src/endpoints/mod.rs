@@ -1,22 +1,58 @@
 pub mod apple_pay;
+pub mod bulk_charge;
+pub mod charge;
 pub mod customers;
 pub mod dedicated_virtual_account;
+pub mod direct_debit;
+pub mod dispute;
+pub mod integration;
+pub mod miscellaneous;
+pub mod order;
+pub mod payment_page;
+pub mod payment_request;
 pub mod plans;
+pub mod product;
+pub mod refund;
+pub mod settlement;
+pub mod storefront;
 pub mod subaccount;
+pub mod subscription;
 pub mod terminal;
 pub mod transaction;
 pub mod transaction_split;
+pub mod transfer;
+pub mod transfer_control;
+pub mod transfer_recipient;
+pub mod verification;
 pub mod virtual_terminal;
 
 // public re-export
 pub use apple_pay::*;
+pub use bulk_charge::*;
+pub use charge::*;
 pub use customers::*;
 pub use dedicated_virtual_account::*;
+pub use direct_debit::*;
+pub use dispute::*;
+pub use integration::*;
+pub use miscellaneous::*;
+pub use order::*;
+pub use payment_page::*;
+pub use payment_request::*;
 pub use plans::*;
+pub use product::*;
+pub use refund::*;
+pub use settlement::*;
+pub use storefront::*;
 pub use subaccount::*;
+pub use subscription::*;
 pub use terminal::*;
 pub use transaction::*;
 pub use transaction_split::*;
+pub use transfer::*;
+pub use transfer_control::*;
+pub use transfer_recipient::*;
+pub use verification::*;
 pub use virtual_terminal::*;
 
 // Const for the base url, since it is used multiple times
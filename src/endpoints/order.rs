@@ -0,0 +1,194 @@
+//! Orders
+//! ======
+//! The Orders API allows you to create and manage orders for products on your storefronts.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    HttpClient, ListOrdersFilter, OrderRequest, OrderResponseData, PaystackAPIError,
+    PaystackResult, Response,
+};
+use std::sync::Arc;
+
+/// A struct to hold all the functions of the orders API endpoint
+#[derive(Debug, Clone)]
+pub struct OrderEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the order route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> OrderEndpoints<T> {
+    /// Creates a new OrderEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new OrderEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> OrderEndpoints<T> {
+        OrderEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new OrderEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new OrderEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> OrderEndpoints<T> {
+        let base_url = format!("{base_url}/order");
+        OrderEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Create an order for selected items
+    ///
+    /// # Arguments
+    /// * `order_request` - The request data to create the order.
+    ///   Should be created with an `OrderRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the order response data or an error
+    pub async fn create_order(
+        &self,
+        order_request: OrderRequest,
+    ) -> PaystackResult<OrderResponseData> {
+        let url = &self.base_url;
+        let body = serde_json::to_value(order_request)
+            .map_err(|e| PaystackAPIError::Order(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Order, e))?;
+
+        let parsed_response: Response<OrderResponseData> =
+            serde_json::from_str(&response).map_err(|e| PaystackAPIError::Order(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// List the orders created on your integration
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the orders returned.
+    ///   It should be created with the `ListOrdersFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of order response data or an error
+    pub async fn list_orders(
+        &self,
+        filter: Option<ListOrdersFilter>,
+    ) -> PaystackResult<Vec<OrderResponseData>> {
+        let url = &self.base_url;
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(from) = filter.from {
+            query.push(("from", from));
+        }
+        if let Some(to) = filter.to {
+            query.push(("to", to));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Order, e))?;
+
+        let parsed_response: Response<Vec<OrderResponseData>> =
+            serde_json::from_str(&response).map_err(|e| PaystackAPIError::Order(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get the details of an order on your integration
+    ///
+    /// # Arguments
+    /// * `order_id` - The ID of the order
+    ///
+    /// # Returns
+    /// A Result containing the order response data or an error
+    pub async fn fetch_order(&self, order_id: u64) -> PaystackResult<OrderResponseData> {
+        let url = format!("{}/{}", self.base_url, order_id);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Order, e))?;
+
+        let parsed_response: Response<OrderResponseData> =
+            serde_json::from_str(&response).map_err(|e| PaystackAPIError::Order(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get the orders of a particular product
+    ///
+    /// # Arguments
+    /// * `product_id` - The ID of the product
+    ///
+    /// # Returns
+    /// A Result containing a vector of order response data or an error
+    pub async fn fetch_product_orders(
+        &self,
+        product_id: u64,
+    ) -> PaystackResult<Vec<OrderResponseData>> {
+        let url = format!("{}/product/{}", self.base_url, product_id);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Order, e))?;
+
+        let parsed_response: Response<Vec<OrderResponseData>> =
+            serde_json::from_str(&response).map_err(|e| PaystackAPIError::Order(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Validate a pay for me order
+    ///
+    /// # Arguments
+    /// * `order_code` - The code of the order
+    ///
+    /// # Returns
+    /// A Result containing the order response data or an error
+    pub async fn validate_pay_for_me_order(
+        &self,
+        order_code: String,
+    ) -> PaystackResult<OrderResponseData> {
+        let url = format!("{}/{}/validate", self.base_url, order_code);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Order, e))?;
+
+        let parsed_response: Response<OrderResponseData> =
+            serde_json::from_str(&response).map_err(|e| PaystackAPIError::Order(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
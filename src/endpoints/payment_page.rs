@@ -0,0 +1,238 @@
+//! Payment Pages
+//! =============
+//! The Payment Pages API provides a quick and secure way to collect payment for products.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    HttpClient, ListPaymentPagesFilter, PaymentPageRequest, PaymentPageResponseData,
+    PaymentPageUpdateRequest, PaystackAPIError, PaystackResult, Response,
+};
+use serde_json::json;
+use std::{marker::PhantomData, sync::Arc};
+
+/// A struct to hold all the functions of the payment pages API endpoint
+#[derive(Debug, Clone)]
+pub struct PaymentPageEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the payment page route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> PaymentPageEndpoints<T> {
+    /// Creates a new PaymentPageEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new PaymentPageEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> PaymentPageEndpoints<T> {
+        PaymentPageEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new PaymentPageEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new PaymentPageEndpoints instance
+    pub fn with_base_url(
+        key: Arc<String>,
+        http: Arc<T>,
+        base_url: &str,
+    ) -> PaymentPageEndpoints<T> {
+        let base_url = format!("{base_url}/page");
+        PaymentPageEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Create a payment page on your integration
+    ///
+    /// # Arguments
+    /// * `page_request` - The request data to create the payment page.
+    ///   Should be created with a `PaymentPageRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the payment page response data or an error
+    pub async fn create_payment_page(
+        &self,
+        page_request: PaymentPageRequest,
+    ) -> PaystackResult<PaymentPageResponseData> {
+        let url = &self.base_url;
+        let body = serde_json::to_value(page_request)
+            .map_err(|e| PaystackAPIError::PaymentPage(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentPage, e))?;
+
+        let parsed_response: Response<PaymentPageResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::PaymentPage(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// List payment pages available on your integration
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the payment pages returned.
+    ///   It should be created with the `ListPaymentPagesFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of payment page response data or an error
+    pub async fn list_payment_pages(
+        &self,
+        filter: Option<ListPaymentPagesFilter>,
+    ) -> PaystackResult<Vec<PaymentPageResponseData>> {
+        let url = &self.base_url;
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(from) = filter.from {
+            query.push(("from", from));
+        }
+        if let Some(to) = filter.to {
+            query.push(("to", to));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentPage, e))?;
+
+        let parsed_response: Response<Vec<PaymentPageResponseData>> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::PaymentPage(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get details of a payment page on your integration
+    ///
+    /// # Arguments
+    /// * `id_or_slug` - The page `ID` or `slug` you want to fetch
+    ///
+    /// # Returns
+    /// A Result containing the payment page response data or an error
+    pub async fn fetch_payment_page(
+        &self,
+        id_or_slug: String,
+    ) -> PaystackResult<PaymentPageResponseData> {
+        let url = format!("{}/{}", self.base_url, id_or_slug);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentPage, e))?;
+
+        let parsed_response: Response<PaymentPageResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::PaymentPage(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Update a payment page details on your integration
+    ///
+    /// # Arguments
+    /// * `id_or_slug` - Page `ID` or `slug`
+    /// * `update_request` - The data to update the payment page with.
+    ///   Should be created with a `PaymentPageUpdateRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the updated payment page response data or an error
+    pub async fn update_payment_page(
+        &self,
+        id_or_slug: String,
+        update_request: PaymentPageUpdateRequest,
+    ) -> PaystackResult<PaymentPageResponseData> {
+        let url = format!("{}/{}", self.base_url, id_or_slug);
+        let body = serde_json::to_value(update_request)
+            .map_err(|e| PaystackAPIError::PaymentPage(e.to_string()))?;
+
+        let response = self
+            .http
+            .put(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentPage, e))?;
+
+        let parsed_response: Response<PaymentPageResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::PaymentPage(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Check the availability of a slug for a payment page
+    ///
+    /// # Arguments
+    /// * `slug` - URL slug to be confirmed
+    ///
+    /// # Returns
+    /// A Result containing a success message if the slug is available, or an error if it is taken
+    pub async fn check_slug_availability(
+        &self,
+        slug: String,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/check_slug_availability/{}", self.base_url, slug);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentPage, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::PaymentPage(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Add products to a payment page
+    ///
+    /// # Arguments
+    /// * `page_id` - ID of the payment page
+    /// * `product_ids` - IDs of the products to add to the page
+    ///
+    /// # Returns
+    /// A Result containing the updated payment page response data or an error
+    pub async fn add_products(
+        &self,
+        page_id: u64,
+        product_ids: Vec<u64>,
+    ) -> PaystackResult<PaymentPageResponseData> {
+        let url = format!("{}/{}/product", self.base_url, page_id);
+        let body = json!({
+            "product": product_ids
+        });
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentPage, e))?;
+
+        let parsed_response: Response<PaymentPageResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::PaymentPage(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
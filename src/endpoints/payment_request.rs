@@ -0,0 +1,319 @@
+//! Payment Requests
+//! ================
+//! The Payment Requests API allows you to manage requests for payment of goods and services.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    CreatePaymentRequest, HttpClient, ListPaymentRequestsFilter, PaymentRequestResponseData,
+    PaymentRequestTotalsData, PaystackAPIError, PaystackResult, Response, UpdatePaymentRequest,
+};
+use serde_json::json;
+use std::{marker::PhantomData, sync::Arc};
+
+/// A struct to hold all the functions of the payment requests API endpoint
+#[derive(Debug, Clone)]
+pub struct PaymentRequestEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the payment request route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> PaymentRequestEndpoints<T> {
+    /// Creates a new PaymentRequestEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new PaymentRequestEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> PaymentRequestEndpoints<T> {
+        PaymentRequestEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new PaymentRequestEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new PaymentRequestEndpoints instance
+    pub fn with_base_url(
+        key: Arc<String>,
+        http: Arc<T>,
+        base_url: &str,
+    ) -> PaymentRequestEndpoints<T> {
+        let base_url = format!("{base_url}/paymentrequest");
+        PaymentRequestEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Create a payment request for a transaction on your integration
+    ///
+    /// # Arguments
+    /// * `payment_request` - The request data to create the payment request.
+    ///   Should be created with a `CreatePaymentRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the payment request response data or an error
+    pub async fn create_payment_request(
+        &self,
+        payment_request: CreatePaymentRequest,
+    ) -> PaystackResult<PaymentRequestResponseData> {
+        let url = &self.base_url;
+        let body = serde_json::to_value(payment_request)
+            .map_err(|e| PaystackAPIError::PaymentRequest(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentRequest, e))?;
+
+        let parsed_response: Response<PaymentRequestResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::PaymentRequest(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// List the payment requests available on your integration
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the payment requests returned.
+    ///   It should be created with the `ListPaymentRequestsFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of payment request response data or an error
+    pub async fn list_payment_requests(
+        &self,
+        filter: Option<ListPaymentRequestsFilter>,
+    ) -> PaystackResult<Vec<PaymentRequestResponseData>> {
+        let url = &self.base_url;
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(customer) = filter.customer {
+            query.push(("customer", customer.to_string()));
+        }
+        if let Some(status) = filter.status {
+            query.push(("status", status.to_string()));
+        }
+        if let Some(currency) = filter.currency {
+            query.push(("currency", currency.to_string()));
+        }
+        if let Some(include_archive) = filter.include_archive {
+            query.push(("include_archive", include_archive.to_string()));
+        }
+        if let Some(from) = filter.from {
+            query.push(("from", from));
+        }
+        if let Some(to) = filter.to {
+            query.push(("to", to));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentRequest, e))?;
+
+        let parsed_response: Response<Vec<PaymentRequestResponseData>> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::PaymentRequest(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get details of a payment request on your integration
+    ///
+    /// # Arguments
+    /// * `id_or_code` - The payment request `ID` or `code` you want to fetch
+    ///
+    /// # Returns
+    /// A Result containing the payment request response data or an error
+    pub async fn fetch_payment_request(
+        &self,
+        id_or_code: String,
+    ) -> PaystackResult<PaymentRequestResponseData> {
+        let url = format!("{}/{}", self.base_url, id_or_code);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentRequest, e))?;
+
+        let parsed_response: Response<PaymentRequestResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::PaymentRequest(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Verify details of a payment request on your integration
+    ///
+    /// # Arguments
+    /// * `code` - Payment request code
+    ///
+    /// # Returns
+    /// A Result containing the payment request response data or an error
+    pub async fn verify_payment_request(
+        &self,
+        code: String,
+    ) -> PaystackResult<PaymentRequestResponseData> {
+        let url = format!("{}/verify/{}", self.base_url, code);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentRequest, e))?;
+
+        let parsed_response: Response<PaymentRequestResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::PaymentRequest(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Send notification of a payment request to your customers
+    ///
+    /// # Arguments
+    /// * `code` - Payment request code
+    ///
+    /// # Returns
+    /// A Result containing a success message if the notification has been sent
+    pub async fn send_notification(&self, code: String) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/notify/{}", self.base_url, code);
+        let body = json!({});
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentRequest, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::PaymentRequest(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get payment requests metric
+    ///
+    /// # Returns
+    /// A Result containing the payment request totals or an error
+    pub async fn payment_request_totals(&self) -> PaystackResult<PaymentRequestTotalsData> {
+        let url = format!("{}/totals", self.base_url);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentRequest, e))?;
+
+        let parsed_response: Response<PaymentRequestTotalsData> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::PaymentRequest(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Finalize a draft payment request
+    ///
+    /// # Arguments
+    /// * `code` - Payment request code
+    /// * `send_notification` - Indicates whether Paystack sends an email notification to the customer
+    ///
+    /// # Returns
+    /// A Result containing the payment request response data or an error
+    pub async fn finalize_payment_request(
+        &self,
+        code: String,
+        send_notification: bool,
+    ) -> PaystackResult<PaymentRequestResponseData> {
+        let url = format!("{}/finalize/{}", self.base_url, code);
+        let body = json!({
+            "send_notification": send_notification
+        });
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentRequest, e))?;
+
+        let parsed_response: Response<PaymentRequestResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::PaymentRequest(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Update a payment request details on your integration
+    ///
+    /// # Arguments
+    /// * `id_or_code` - Payment request `ID` or `code`
+    /// * `update_request` - The data to update the payment request with.
+    ///   Should be created with an `UpdatePaymentRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the updated payment request response data or an error
+    pub async fn update_payment_request(
+        &self,
+        id_or_code: String,
+        update_request: UpdatePaymentRequest,
+    ) -> PaystackResult<PaymentRequestResponseData> {
+        let url = format!("{}/{}", self.base_url, id_or_code);
+        let body = serde_json::to_value(update_request)
+            .map_err(|e| PaystackAPIError::PaymentRequest(e.to_string()))?;
+
+        let response = self
+            .http
+            .put(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentRequest, e))?;
+
+        let parsed_response: Response<PaymentRequestResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::PaymentRequest(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Used to archive a payment request. A payment request will no longer be fetched on list or returned on verify
+    ///
+    /// # Arguments
+    /// * `code` - Payment request code
+    ///
+    /// # Returns
+    /// A Result containing a success message if the payment request has been archived
+    pub async fn archive_payment_request(
+        &self,
+        code: String,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/archive/{}", self.base_url, code);
+        let body = json!({});
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::PaymentRequest, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::PaymentRequest(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
@@ -25,7 +25,20 @@ pub struct PlansEndpoints<T: HttpClient + Default> {
 /// A new PlansEndpoints instance
 impl<T: HttpClient + Default> PlansEndpoints<T> {
     pub fn new(key: Arc<String>, http: Arc<T>) -> PlansEndpoints<T> {
-        let base_url = format!("{PAYSTACK_BASE_URL}/plan");
+        PlansEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new PlansEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new PlansEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> PlansEndpoints<T> {
+        let base_url = format!("{base_url}/plan");
         PlansEndpoints {
             key: key.to_string(),
             base_url,
@@ -50,7 +63,7 @@ impl<T: HttpClient + Default> PlansEndpoints<T> {
             .http
             .post(url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Plan(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Plan, e))?;
 
         let parsed_response: Response<PlanResponseData> =
             serde_json::from_str(&response).map_err(|e| PaystackAPIError::Plan(e.to_string()))?;
@@ -105,7 +118,7 @@ impl<T: HttpClient + Default> PlansEndpoints<T> {
             .http
             .get(url, &self.key, Some(&query))
             .await
-            .map_err(|e| PaystackAPIError::Plan(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Plan, e))?;
 
         let parsed_response: Response<Vec<PlanResponseData>> =
             serde_json::from_str(&response).map_err(|e| PaystackAPIError::Plan(e.to_string()))?;
@@ -127,7 +140,7 @@ impl<T: HttpClient + Default> PlansEndpoints<T> {
             .http
             .get(&url, &self.key, None)
             .await
-            .map_err(|e| PaystackAPIError::Plan(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Plan, e))?;
 
         let parsed_response: Response<PlanResponseData> =
             serde_json::from_str(&response).map_err(|e| PaystackAPIError::Plan(e.to_string()))?;
@@ -157,7 +170,7 @@ impl<T: HttpClient + Default> PlansEndpoints<T> {
             .http
             .put(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Plan(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Plan, e))?;
 
         let parsed_response: Response<PhantomData<String>> =
             serde_json::from_str(&response).map_err(|e| PaystackAPIError::Plan(e.to_string()))?;
@@ -0,0 +1,175 @@
+//! Products
+//! ========
+//! The Products API allows you to create and manage inventories on your integration.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    HttpClient, ListProductsFilter, PaystackAPIError, PaystackResult, ProductRequest,
+    ProductResponseData, ProductUpdateRequest, Response,
+};
+use std::sync::Arc;
+
+/// A struct to hold all the functions of the products API endpoint
+#[derive(Debug, Clone)]
+pub struct ProductEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the product route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> ProductEndpoints<T> {
+    /// Creates a new ProductEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new ProductEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> ProductEndpoints<T> {
+        ProductEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new ProductEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new ProductEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> ProductEndpoints<T> {
+        let base_url = format!("{base_url}/product");
+        ProductEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Create a product on your integration
+    ///
+    /// # Arguments
+    /// * `product_request` - The request data to create the product.
+    ///   Should be created with a `ProductRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the product response data or an error
+    pub async fn create_product(
+        &self,
+        product_request: ProductRequest,
+    ) -> PaystackResult<ProductResponseData> {
+        let url = &self.base_url;
+        let body = serde_json::to_value(product_request)
+            .map_err(|e| PaystackAPIError::Product(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Product, e))?;
+
+        let parsed_response: Response<ProductResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Product(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// List products available on your integration
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the products returned.
+    ///   It should be created with the `ListProductsFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of product response data or an error
+    pub async fn list_products(
+        &self,
+        filter: Option<ListProductsFilter>,
+    ) -> PaystackResult<Vec<ProductResponseData>> {
+        let url = &self.base_url;
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(from) = filter.from {
+            query.push(("from", from));
+        }
+        if let Some(to) = filter.to {
+            query.push(("to", to));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Product, e))?;
+
+        let parsed_response: Response<Vec<ProductResponseData>> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::Product(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get details of a product on your integration
+    ///
+    /// # Arguments
+    /// * `id` - The product ID you want to fetch
+    ///
+    /// # Returns
+    /// A Result containing the product response data or an error
+    pub async fn fetch_product(&self, id: u64) -> PaystackResult<ProductResponseData> {
+        let url = format!("{}/{}", self.base_url, id);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Product, e))?;
+
+        let parsed_response: Response<ProductResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Product(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Update a product details on your integration
+    ///
+    /// # Arguments
+    /// * `id` - Product ID
+    /// * `update_request` - The data to update the product with.
+    ///   Should be created with a `ProductUpdateRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the updated product response data or an error
+    pub async fn update_product(
+        &self,
+        id: u64,
+        update_request: ProductUpdateRequest,
+    ) -> PaystackResult<ProductResponseData> {
+        let url = format!("{}/{}", self.base_url, id);
+        let body = serde_json::to_value(update_request)
+            .map_err(|e| PaystackAPIError::Product(e.to_string()))?;
+
+        let response = self
+            .http
+            .put(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Product, e))?;
+
+        let parsed_response: Response<ProductResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Product(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
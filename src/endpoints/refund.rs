@@ -0,0 +1,150 @@
+//! Refunds
+//! =======
+//! The Refunds API allows you to create and manage transaction refunds.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    HttpClient, ListRefundsFilter, PaystackAPIError, PaystackResult, RefundRequest,
+    RefundResponseData, Response,
+};
+use std::sync::Arc;
+
+/// A struct to hold all the functions of the refunds API endpoint
+#[derive(Debug, Clone)]
+pub struct RefundEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the refund route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> RefundEndpoints<T> {
+    /// Creates a new RefundEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new RefundEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> RefundEndpoints<T> {
+        RefundEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new RefundEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new RefundEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> RefundEndpoints<T> {
+        let base_url = format!("{base_url}/refund");
+        RefundEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Initiate a refund on your integration
+    ///
+    /// # Arguments
+    /// * `refund_request` - The request data to create the refund.
+    ///   Should be created with a `RefundRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the refund response data or an error
+    pub async fn create_refund(
+        &self,
+        refund_request: RefundRequest,
+    ) -> PaystackResult<RefundResponseData> {
+        let url = &self.base_url;
+        let body = serde_json::to_value(refund_request)
+            .map_err(|e| PaystackAPIError::Refund(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Refund, e))?;
+
+        let parsed_response: Response<RefundResponseData> =
+            serde_json::from_str(&response).map_err(|e| PaystackAPIError::Refund(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// List refunds available on your integration
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the refunds returned.
+    ///   It should be created with the `ListRefundsFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of refund response data or an error
+    pub async fn list_refunds(
+        &self,
+        filter: Option<ListRefundsFilter>,
+    ) -> PaystackResult<Vec<RefundResponseData>> {
+        let url = &self.base_url;
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(transaction) = filter.transaction {
+            query.push(("transaction", transaction));
+        }
+        if let Some(currency) = filter.currency {
+            query.push(("currency", currency.to_string()));
+        }
+        if let Some(from) = filter.from {
+            query.push(("from", from));
+        }
+        if let Some(to) = filter.to {
+            query.push(("to", to));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Refund, e))?;
+
+        let parsed_response: Response<Vec<RefundResponseData>> =
+            serde_json::from_str(&response).map_err(|e| PaystackAPIError::Refund(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get details of a refund on your integration
+    ///
+    /// # Arguments
+    /// * `refund_id` - ID of the refund
+    ///
+    /// # Returns
+    /// A Result containing the refund response data or an error
+    pub async fn fetch_refund(&self, refund_id: u64) -> PaystackResult<RefundResponseData> {
+        let url = format!("{}/{}", self.base_url, refund_id);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Refund, e))?;
+
+        let parsed_response: Response<RefundResponseData> =
+            serde_json::from_str(&response).map_err(|e| PaystackAPIError::Refund(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
@@ -0,0 +1,143 @@
+//! Settlements
+//! ===========
+//! The Settlements API allows you to gain insights into payouts made by Paystack to your bank account.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    HttpClient, ListSettlementTransactionsFilter, ListSettlementsFilter, PaystackAPIError,
+    PaystackResult, Response, SettlementData, TransactionStatusData,
+};
+use std::sync::Arc;
+
+/// A struct to hold all the functions of the settlements API endpoint
+#[derive(Debug, Clone)]
+pub struct SettlementEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the settlement route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> SettlementEndpoints<T> {
+    /// Creates a new SettlementEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new SettlementEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> SettlementEndpoints<T> {
+        SettlementEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new SettlementEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new SettlementEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> SettlementEndpoints<T> {
+        let base_url = format!("{base_url}/settlement");
+        SettlementEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// List settlements made to your settlement accounts
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the settlements returned.
+    ///   It should be created with the `ListSettlementsFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of settlement data or an error
+    pub async fn list_settlements(
+        &self,
+        filter: Option<ListSettlementsFilter>,
+    ) -> PaystackResult<Vec<SettlementData>> {
+        let url = &self.base_url;
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(status) = filter.status {
+            query.push(("status", status.to_string()));
+        }
+        if let Some(subaccount) = filter.subaccount {
+            query.push(("subaccount", subaccount));
+        }
+        if let Some(from) = filter.from {
+            query.push(("from", from));
+        }
+        if let Some(to) = filter.to {
+            query.push(("to", to));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Settlement, e))?;
+
+        let parsed_response: Response<Vec<SettlementData>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Settlement(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get the transactions that make up a particular settlement
+    ///
+    /// # Arguments
+    /// * `settlement_id` - The settlement ID in which you want to fetch its transactions
+    /// * `filter` - Optional set of parameters to filter the transactions returned.
+    ///   It should be created with the `ListSettlementTransactionsFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of transaction status data or an error
+    pub async fn list_settlement_transactions(
+        &self,
+        settlement_id: u64,
+        filter: Option<ListSettlementTransactionsFilter>,
+    ) -> PaystackResult<Vec<TransactionStatusData>> {
+        let url = format!("{}/{}/transactions", self.base_url, settlement_id);
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(from) = filter.from {
+            query.push(("from", from));
+        }
+        if let Some(to) = filter.to {
+            query.push(("to", to));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(&url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Settlement, e))?;
+
+        let parsed_response: Response<Vec<TransactionStatusData>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Settlement(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
@@ -0,0 +1,331 @@
+//! Storefronts
+//! ===========
+//! The Storefronts API allows you to create and manage online stores for your products.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    HttpClient, ListStorefrontsFilter, OrderResponseData, PaystackAPIError, PaystackResult,
+    Response, StorefrontRequest, StorefrontResponseData, StorefrontUpdateRequest,
+};
+use serde_json::json;
+use std::{marker::PhantomData, sync::Arc};
+
+/// A struct to hold all the functions of the storefronts API endpoint
+#[derive(Debug, Clone)]
+pub struct StorefrontEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the storefront route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> StorefrontEndpoints<T> {
+    /// Creates a new StorefrontEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new StorefrontEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> StorefrontEndpoints<T> {
+        StorefrontEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new StorefrontEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new StorefrontEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> StorefrontEndpoints<T> {
+        let base_url = format!("{base_url}/storefront");
+        StorefrontEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Create a storefront on your integration
+    ///
+    /// # Arguments
+    /// * `storefront_request` - The request data to create the storefront.
+    ///   Should be created with a `StorefrontRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the storefront response data or an error
+    pub async fn create_storefront(
+        &self,
+        storefront_request: StorefrontRequest,
+    ) -> PaystackResult<StorefrontResponseData> {
+        let url = &self.base_url;
+        let body = serde_json::to_value(storefront_request)
+            .map_err(|e| PaystackAPIError::Storefront(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Storefront, e))?;
+
+        let parsed_response: Response<StorefrontResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Storefront(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// List the storefronts created on your integration
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the storefronts returned.
+    ///   It should be created with the `ListStorefrontsFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of storefront response data or an error
+    pub async fn list_storefronts(
+        &self,
+        filter: Option<ListStorefrontsFilter>,
+    ) -> PaystackResult<Vec<StorefrontResponseData>> {
+        let url = &self.base_url;
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(status) = filter.status {
+            query.push(("status", status));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Storefront, e))?;
+
+        let parsed_response: Response<Vec<StorefrontResponseData>> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::Storefront(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get the details of a storefront on your integration
+    ///
+    /// # Arguments
+    /// * `storefront_id` - The ID of the storefront
+    ///
+    /// # Returns
+    /// A Result containing the storefront response data or an error
+    pub async fn fetch_storefront(
+        &self,
+        storefront_id: u64,
+    ) -> PaystackResult<StorefrontResponseData> {
+        let url = format!("{}/{}", self.base_url, storefront_id);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Storefront, e))?;
+
+        let parsed_response: Response<StorefrontResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Storefront(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Update the details of a storefront on your integration
+    ///
+    /// # Arguments
+    /// * `storefront_id` - The ID of the storefront
+    /// * `update_request` - The data to update the storefront with.
+    ///   Should be created with a `StorefrontUpdateRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the updated storefront response data or an error
+    pub async fn update_storefront(
+        &self,
+        storefront_id: u64,
+        update_request: StorefrontUpdateRequest,
+    ) -> PaystackResult<StorefrontResponseData> {
+        let url = format!("{}/{}", self.base_url, storefront_id);
+        let body = serde_json::to_value(update_request)
+            .map_err(|e| PaystackAPIError::Storefront(e.to_string()))?;
+
+        let response = self
+            .http
+            .put(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Storefront, e))?;
+
+        let parsed_response: Response<StorefrontResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Storefront(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Delete a storefront on your integration
+    ///
+    /// # Arguments
+    /// * `storefront_id` - The ID of the storefront
+    ///
+    /// # Returns
+    /// A Result containing a success message if the storefront has been deleted
+    pub async fn delete_storefront(
+        &self,
+        storefront_id: u64,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/{}", self.base_url, storefront_id);
+        let body = json!({}); // empty body since the route takes none.
+
+        let response = self
+            .http
+            .delete(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Storefront, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Storefront(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Verify the availability of a slug before using it for your storefront
+    ///
+    /// # Arguments
+    /// * `slug` - The slug to verify
+    ///
+    /// # Returns
+    /// A Result containing a success message if the slug is available, or an error if it is taken
+    pub async fn verify_slug(&self, slug: String) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/verify/{}", self.base_url, slug);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Storefront, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Storefront(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Fetch all orders in your storefront
+    ///
+    /// # Arguments
+    /// * `storefront_id` - The ID of the storefront
+    ///
+    /// # Returns
+    /// A Result containing a vector of order response data or an error
+    pub async fn fetch_storefront_orders(
+        &self,
+        storefront_id: u64,
+    ) -> PaystackResult<Vec<OrderResponseData>> {
+        let url = format!("{}/{}/order", self.base_url, storefront_id);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Storefront, e))?;
+
+        let parsed_response: Response<Vec<OrderResponseData>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Storefront(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Add previously created products to a storefront
+    ///
+    /// # Arguments
+    /// * `storefront_id` - The ID of the storefront
+    /// * `product_ids` - IDs of the products to add to the storefront
+    ///
+    /// # Returns
+    /// A Result containing a success message if the products have been added
+    pub async fn add_products(
+        &self,
+        storefront_id: u64,
+        product_ids: Vec<u64>,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/{}/product", self.base_url, storefront_id);
+        let body = json!({
+            "products": product_ids
+        });
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Storefront, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Storefront(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Make your storefront publicly available
+    ///
+    /// # Arguments
+    /// * `storefront_id` - The ID of the storefront
+    ///
+    /// # Returns
+    /// A Result containing a success message if the storefront has been published
+    pub async fn publish_storefront(
+        &self,
+        storefront_id: u64,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/{}/publish", self.base_url, storefront_id);
+        let body = json!({});
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Storefront, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Storefront(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Duplicate an existing storefront
+    ///
+    /// # Arguments
+    /// * `storefront_id` - The ID of the storefront to duplicate
+    ///
+    /// # Returns
+    /// A Result containing the new storefront response data or an error
+    pub async fn duplicate_storefront(
+        &self,
+        storefront_id: u64,
+    ) -> PaystackResult<StorefrontResponseData> {
+        let url = format!("{}/{}/duplicate", self.base_url, storefront_id);
+        let body = json!({});
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Storefront, e))?;
+
+        let parsed_response: Response<StorefrontResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Storefront(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
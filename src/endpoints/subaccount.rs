@@ -31,7 +31,20 @@ impl<T: HttpClient + Default> SubaccountEndpoints<T> {
     /// # Returns
     /// A new SubaccountEndpoints instance
     pub fn new(key: Arc<String>, http: Arc<T>) -> SubaccountEndpoints<T> {
-        let base_url = format!("{PAYSTACK_BASE_URL}/subaccount");
+        SubaccountEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new SubaccountEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new SubaccountEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> SubaccountEndpoints<T> {
+        let base_url = format!("{base_url}/subaccount");
         SubaccountEndpoints {
             key: key.to_string(),
             base_url,
@@ -59,7 +72,7 @@ impl<T: HttpClient + Default> SubaccountEndpoints<T> {
             .http
             .post(url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Subaccount(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Subaccount, e))?;
 
         let parsed_response: Response<SubaccountsResponseData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Subaccount(e.to_string()))?;
@@ -89,7 +102,7 @@ impl<T: HttpClient + Default> SubaccountEndpoints<T> {
             .http
             .get(&url, &self.key, Some(&query))
             .await
-            .map_err(|e| PaystackAPIError::Subaccount(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Subaccount, e))?;
 
         let parsed_response: Response<Vec<SubaccountsResponseData>> =
             serde_json::from_str(&response)
@@ -115,7 +128,7 @@ impl<T: HttpClient + Default> SubaccountEndpoints<T> {
             .http
             .get(&url, &self.key, None)
             .await
-            .map_err(|e| PaystackAPIError::Subaccount(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Subaccount, e))?;
 
         let parsed_response: Response<SubaccountsResponseData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Subaccount(e.to_string()))?;
@@ -145,7 +158,7 @@ impl<T: HttpClient + Default> SubaccountEndpoints<T> {
             .http
             .put(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Subaccount(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Subaccount, e))?;
 
         let parsed_response: Response<SubaccountsResponseData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Subaccount(e.to_string()))?;
@@ -0,0 +1,262 @@
+//! Subscriptions
+//! =============
+//! The Subscriptions API allows you to create and manage recurring payment on your integration.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    HttpClient, ListSubscriptionsFilter, PaystackAPIError, PaystackResult, Response, Subscription,
+    SubscriptionLinkData, SubscriptionRequest,
+};
+use serde_json::json;
+use std::{marker::PhantomData, sync::Arc};
+
+/// A struct to hold all the functions of the subscriptions API endpoint
+#[derive(Debug, Clone)]
+pub struct SubscriptionEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the subscription route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> SubscriptionEndpoints<T> {
+    /// Creates a new SubscriptionEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new SubscriptionEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> SubscriptionEndpoints<T> {
+        SubscriptionEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new SubscriptionEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new SubscriptionEndpoints instance
+    pub fn with_base_url(
+        key: Arc<String>,
+        http: Arc<T>,
+        base_url: &str,
+    ) -> SubscriptionEndpoints<T> {
+        let base_url = format!("{base_url}/subscription");
+        SubscriptionEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Create a subscription on your integration
+    ///
+    /// # Arguments
+    /// * `subscription_request` - The request data to create the subscription.
+    ///   Should be created with a `SubscriptionRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the subscription or an error
+    pub async fn create_subscription(
+        &self,
+        subscription_request: SubscriptionRequest,
+    ) -> PaystackResult<Subscription> {
+        let url = &self.base_url;
+        let body = serde_json::to_value(subscription_request)
+            .map_err(|e| PaystackAPIError::Subscription(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Subscription, e))?;
+
+        let parsed_response: Response<Subscription> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Subscription(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// List subscriptions available on your integration
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the subscriptions returned.
+    ///   It should be created with the `ListSubscriptionsFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of subscriptions or an error
+    pub async fn list_subscriptions(
+        &self,
+        filter: Option<ListSubscriptionsFilter>,
+    ) -> PaystackResult<Vec<Subscription>> {
+        let url = &self.base_url;
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(customer) = filter.customer {
+            query.push(("customer", customer.to_string()));
+        }
+        if let Some(plan) = filter.plan {
+            query.push(("plan", plan.to_string()));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Subscription, e))?;
+
+        let parsed_response: Response<Vec<Subscription>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Subscription(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get details of a subscription on your integration
+    ///
+    /// # Arguments
+    /// * `id_or_code` - The subscription `ID` or `code` you want to fetch
+    ///
+    /// # Returns
+    /// A Result containing the subscription or an error
+    pub async fn fetch_subscription(&self, id_or_code: String) -> PaystackResult<Subscription> {
+        let url = format!("{}/{}", self.base_url, id_or_code);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Subscription, e))?;
+
+        let parsed_response: Response<Subscription> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Subscription(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Enable a subscription on your integration
+    ///
+    /// # Arguments
+    /// * `code` - Subscription code
+    /// * `email_token` - Email token, found in the `email_token` field of the subscription
+    ///
+    /// # Returns
+    /// A Result containing a success message if the subscription has been enabled
+    pub async fn enable_subscription(
+        &self,
+        code: String,
+        email_token: String,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/enable", self.base_url);
+        let body = json!({
+            "code": code,
+            "token": email_token
+        });
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Subscription, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Subscription(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Disable a subscription on your integration
+    ///
+    /// # Arguments
+    /// * `code` - Subscription code
+    /// * `email_token` - Email token, found in the `email_token` field of the subscription
+    ///
+    /// # Returns
+    /// A Result containing a success message if the subscription has been disabled
+    pub async fn disable_subscription(
+        &self,
+        code: String,
+        email_token: String,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/disable", self.base_url);
+        let body = json!({
+            "code": code,
+            "token": email_token
+        });
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Subscription, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Subscription(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Generate a link for updating the card on a subscription
+    ///
+    /// # Arguments
+    /// * `code` - Subscription code
+    ///
+    /// # Returns
+    /// A Result containing the update link or an error
+    pub async fn generate_update_subscription_link(
+        &self,
+        code: String,
+    ) -> PaystackResult<SubscriptionLinkData> {
+        let url = format!("{}/{}/manage/link", self.base_url, code);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Subscription, e))?;
+
+        let parsed_response: Response<SubscriptionLinkData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Subscription(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Email a customer a link for updating the card on their subscription
+    ///
+    /// # Arguments
+    /// * `code` - Subscription code
+    ///
+    /// # Returns
+    /// A Result containing a success message if the email has been sent
+    pub async fn send_update_subscription_link(
+        &self,
+        code: String,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/{}/manage/email", self.base_url, code);
+        let body = json!({});
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Subscription, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Subscription(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
@@ -32,7 +32,20 @@ impl<T: HttpClient + Default> TerminalEndpoints<T> {
     /// # Returns
     /// A new TerminalEndpoints instance
     pub fn new(key: Arc<String>, http: Arc<T>) -> TerminalEndpoints<T> {
-        let base_url = format!("{PAYSTACK_BASE_URL}/terminal");
+        TerminalEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new TerminalEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new TerminalEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> TerminalEndpoints<T> {
+        let base_url = format!("{base_url}/terminal");
         TerminalEndpoints {
             key: key.to_string(),
             base_url,
@@ -61,7 +74,7 @@ impl<T: HttpClient + Default> TerminalEndpoints<T> {
             .http
             .post(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Terminal, e))?;
 
         let parsed_response: Response<SendEventResponseData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
@@ -88,7 +101,7 @@ impl<T: HttpClient + Default> TerminalEndpoints<T> {
             .http
             .get(&url, &self.key, None)
             .await
-            .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Terminal, e))?;
 
         let parsed_response: Response<FetchEventStatusResponseData> =
             serde_json::from_str(&response)
@@ -114,7 +127,7 @@ impl<T: HttpClient + Default> TerminalEndpoints<T> {
             .http
             .get(&url, &self.key, None)
             .await
-            .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Terminal, e))?;
 
         let parsed_response: Response<FetchTerminalStatusResponseData> =
             serde_json::from_str(&response)
@@ -139,7 +152,7 @@ impl<T: HttpClient + Default> TerminalEndpoints<T> {
             .http
             .get(url, &self.key, Some(&query))
             .await
-            .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Terminal, e))?;
 
         let parsed_response: Response<Vec<TerminalData>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
@@ -161,7 +174,7 @@ impl<T: HttpClient + Default> TerminalEndpoints<T> {
             .http
             .get(&url, &self.key, None)
             .await
-            .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Terminal, e))?;
 
         let parsed_response: Response<TerminalData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
@@ -190,7 +203,7 @@ impl<T: HttpClient + Default> TerminalEndpoints<T> {
             .http
             .put(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Terminal, e))?;
 
         let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
@@ -218,7 +231,7 @@ impl<T: HttpClient + Default> TerminalEndpoints<T> {
             .http
             .post(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Terminal, e))?;
 
         let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
@@ -246,7 +259,7 @@ impl<T: HttpClient + Default> TerminalEndpoints<T> {
             .http
             .post(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Terminal, e))?;
 
         let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Terminal(e.to_string()))?;
@@ -32,7 +32,24 @@ impl<T: HttpClient + Default> TransactionEndpoints<T> {
     /// # Returns
     /// A new TransactionEndpoints instance
     pub fn new(key: Arc<String>, http: Arc<T>) -> TransactionEndpoints<T> {
-        let base_url = format!("{PAYSTACK_BASE_URL}/transaction");
+        TransactionEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new TransactionEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new TransactionEndpoints instance
+    pub fn with_base_url(
+        key: Arc<String>,
+        http: Arc<T>,
+        base_url: &str,
+    ) -> TransactionEndpoints<T> {
+        let base_url = format!("{base_url}/transaction");
         TransactionEndpoints {
             key: key.to_string(),
             base_url,
@@ -60,7 +77,7 @@ impl<T: HttpClient + Default> TransactionEndpoints<T> {
             .http
             .post(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transaction, e))?;
 
         let parsed_response: Response<TransactionResponseData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
@@ -84,7 +101,7 @@ impl<T: HttpClient + Default> TransactionEndpoints<T> {
             .http
             .get(&url, &self.key, None)
             .await
-            .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transaction, e))?;
 
         let parsed_response: Response<TransactionStatusData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
@@ -115,7 +132,7 @@ impl<T: HttpClient + Default> TransactionEndpoints<T> {
             .http
             .get(url, &self.key, Some(&query))
             .await
-            .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transaction, e))?;
 
         let parsed_response: Response<Vec<TransactionStatusData>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
@@ -140,7 +157,7 @@ impl<T: HttpClient + Default> TransactionEndpoints<T> {
             .http
             .get(&url, &self.key, None)
             .await
-            .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transaction, e))?;
 
         let parsed_response: Response<TransactionStatusData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
@@ -168,7 +185,7 @@ impl<T: HttpClient + Default> TransactionEndpoints<T> {
             .http
             .post(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transaction, e))?;
 
         let parsed_response: Response<ChargeResponseData> =
             serde_json::from_str(&response).map_err(|e| PaystackAPIError::Charge(e.to_string()))?;
@@ -200,7 +217,7 @@ impl<T: HttpClient + Default> TransactionEndpoints<T> {
             .http
             .get(&url, &self.key, None)
             .await
-            .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transaction, e))?;
 
         let parsed_response: Response<TransactionTimelineData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
@@ -219,7 +236,7 @@ impl<T: HttpClient + Default> TransactionEndpoints<T> {
             .http
             .get(&url, &self.key, None)
             .await
-            .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transaction, e))?;
 
         let parsed_response: Response<TransactionTotalData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
@@ -263,7 +280,7 @@ impl<T: HttpClient + Default> TransactionEndpoints<T> {
             .http
             .get(&url, &self.key, Some(&query))
             .await
-            .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transaction, e))?;
 
         let parsed_response = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
@@ -291,7 +308,7 @@ impl<T: HttpClient + Default> TransactionEndpoints<T> {
             .http
             .post(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transaction, e))?;
 
         let parsed_response: Response<TransactionStatusData> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::Transaction(e.to_string()))?;
@@ -31,7 +31,24 @@ impl<T: HttpClient + Default> TransactionSplitEndpoints<T> {
     /// # Returns
     /// A new TransactionSplitEndpoints instance
     pub fn new(key: Arc<String>, http: Arc<T>) -> TransactionSplitEndpoints<T> {
-        let base_url = format!("{PAYSTACK_BASE_URL}/split");
+        TransactionSplitEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new TransactionSplitEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new TransactionSplitEndpoints instance
+    pub fn with_base_url(
+        key: Arc<String>,
+        http: Arc<T>,
+        base_url: &str,
+    ) -> TransactionSplitEndpoints<T> {
+        let base_url = format!("{base_url}/split");
         TransactionSplitEndpoints {
             key: key.to_string(),
             base_url,
@@ -59,7 +76,7 @@ impl<T: HttpClient + Default> TransactionSplitEndpoints<T> {
             .http
             .post(url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::TransactionSplit(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransactionSplit, e))?;
 
         let parsed_response: Response<TransactionSplitResponseData> =
             serde_json::from_str(&response)
@@ -97,7 +114,7 @@ impl<T: HttpClient + Default> TransactionSplitEndpoints<T> {
             .http
             .get(url, &self.key, Some(&query))
             .await
-            .map_err(|e| PaystackAPIError::TransactionSplit(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransactionSplit, e))?;
 
         let parsed_response: Response<Vec<TransactionSplitResponseData>> =
             serde_json::from_str(&response)
@@ -123,7 +140,7 @@ impl<T: HttpClient + Default> TransactionSplitEndpoints<T> {
             .http
             .get(&url, &self.key, None)
             .await
-            .map_err(|e| PaystackAPIError::TransactionSplit(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransactionSplit, e))?;
 
         let parsed_response: Response<TransactionSplitResponseData> =
             serde_json::from_str(&response)
@@ -154,7 +171,7 @@ impl<T: HttpClient + Default> TransactionSplitEndpoints<T> {
             .http
             .put(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::TransactionSplit(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransactionSplit, e))?;
 
         let parsed_response: Response<TransactionSplitResponseData> =
             serde_json::from_str(&response)
@@ -184,7 +201,7 @@ impl<T: HttpClient + Default> TransactionSplitEndpoints<T> {
             .http
             .post(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::TransactionSplit(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransactionSplit, e))?;
 
         let parsed_response: Response<TransactionSplitResponseData> =
             serde_json::from_str(&response)
@@ -215,7 +232,7 @@ impl<T: HttpClient + Default> TransactionSplitEndpoints<T> {
             .http
             .post(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::TransactionSplit(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransactionSplit, e))?;
 
         let parsed_response: Response<String> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::TransactionSplit(e.to_string()))?;
@@ -0,0 +1,232 @@
+//! Transfers
+//! =========
+//! The Transfers API allows you to automate sending money to your customers.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    BulkTransferRequest, BulkTransferResponseData, HttpClient, ListTransfersFilter,
+    PaystackAPIError, PaystackResult, Response, TransferRequest, TransferResponseData,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+/// A struct to hold all the functions of the transfers API endpoint
+#[derive(Debug, Clone)]
+pub struct TransferEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the transfer route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> TransferEndpoints<T> {
+    /// Creates a new TransferEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new TransferEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> TransferEndpoints<T> {
+        TransferEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new TransferEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new TransferEndpoints instance
+    pub fn with_base_url(key: Arc<String>, http: Arc<T>, base_url: &str) -> TransferEndpoints<T> {
+        let base_url = format!("{base_url}/transfer");
+        TransferEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Send money to your customers.
+    ///
+    /// # Arguments
+    /// * `transfer_request` - The request data to initiate the transfer.
+    ///   Should be created with a `TransferRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the transfer response data or an error
+    pub async fn initiate_transfer(
+        &self,
+        transfer_request: TransferRequest,
+    ) -> PaystackResult<TransferResponseData> {
+        let url = &self.base_url;
+        let body = serde_json::to_value(transfer_request)
+            .map_err(|e| PaystackAPIError::Transfer(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transfer, e))?;
+
+        let parsed_response: Response<TransferResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Transfer(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Finalize an initiated transfer which requires an OTP.
+    ///
+    /// # Arguments
+    /// * `transfer_code` - The transfer code you want to finalize
+    /// * `otp` - OTP sent to business phone to verify transfer
+    ///
+    /// # Returns
+    /// A Result containing the transfer response data or an error
+    pub async fn finalize_transfer(
+        &self,
+        transfer_code: String,
+        otp: String,
+    ) -> PaystackResult<TransferResponseData> {
+        let url = format!("{}/finalize_transfer", self.base_url);
+        let body = json!({
+            "transfer_code": transfer_code,
+            "otp": otp
+        });
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transfer, e))?;
+
+        let parsed_response: Response<TransferResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Transfer(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Batch multiple transfers in a single request.
+    ///
+    /// You need to disable the Transfers OTP requirement to use this endpoint.
+    ///
+    /// # Arguments
+    /// * `bulk_transfer_request` - The request data containing the transfers to initiate.
+    ///   Should be created with a `BulkTransferRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of the queued transfers or an error
+    pub async fn initiate_bulk_transfer(
+        &self,
+        bulk_transfer_request: BulkTransferRequest,
+    ) -> PaystackResult<Vec<BulkTransferResponseData>> {
+        let url = format!("{}/bulk", self.base_url);
+        let body = serde_json::to_value(bulk_transfer_request)
+            .map_err(|e| PaystackAPIError::Transfer(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transfer, e))?;
+
+        let parsed_response: Response<Vec<BulkTransferResponseData>> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::Transfer(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// List the transfers made on your integration.
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the transfers returned.
+    ///   It should be created with the `ListTransfersFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of transfer response data or an error
+    pub async fn list_transfers(
+        &self,
+        filter: Option<ListTransfersFilter>,
+    ) -> PaystackResult<Vec<TransferResponseData>> {
+        let url = &self.base_url;
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(recipient) = filter.recipient {
+            query.push(("recipient", recipient.to_string()));
+        }
+        if let Some(from) = filter.from {
+            query.push(("from", from));
+        }
+        if let Some(to) = filter.to {
+            query.push(("to", to));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transfer, e))?;
+
+        let parsed_response: Response<Vec<TransferResponseData>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Transfer(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get details of a transfer on your integration.
+    ///
+    /// # Arguments
+    /// * `id_or_code` - The transfer ID or code you want to fetch
+    ///
+    /// # Returns
+    /// A Result containing the transfer response data or an error
+    pub async fn fetch_transfer(&self, id_or_code: String) -> PaystackResult<TransferResponseData> {
+        let url = format!("{}/{}", self.base_url, id_or_code);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transfer, e))?;
+
+        let parsed_response: Response<TransferResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Transfer(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Verify the status of a transfer on your integration.
+    ///
+    /// # Arguments
+    /// * `reference` - Transfer reference
+    ///
+    /// # Returns
+    /// A Result containing the transfer response data or an error
+    pub async fn verify_transfer(&self, reference: String) -> PaystackResult<TransferResponseData> {
+        let url = format!("{}/verify/{}", self.base_url, reference);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Transfer, e))?;
+
+        let parsed_response: Response<TransferResponseData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Transfer(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
@@ -0,0 +1,217 @@
+//! Transfers Control
+//! =================
+//! The Transfers Control API allows you to manage settings of your transfers.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    BalanceData, BalanceLedgerData, HttpClient, ListBalanceLedgerFilter, PaystackAPIError,
+    PaystackResult, ResendOtpReason, Response,
+};
+use serde_json::json;
+use std::{marker::PhantomData, sync::Arc};
+
+/// A struct to hold all the functions of the transfers control API endpoint
+#[derive(Debug, Clone)]
+pub struct TransferControlEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the transfers control route.
+    /// The routes are split between `/balance` and `/transfer`
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> TransferControlEndpoints<T> {
+    /// Creates a new TransferControlEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new TransferControlEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> TransferControlEndpoints<T> {
+        TransferControlEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new TransferControlEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new TransferControlEndpoints instance
+    pub fn with_base_url(
+        key: Arc<String>,
+        http: Arc<T>,
+        base_url: &str,
+    ) -> TransferControlEndpoints<T> {
+        let base_url = base_url.to_string();
+        TransferControlEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Fetch the available balance on your integration
+    ///
+    /// # Returns
+    /// A Result containing the balance of each currency on your integration or an error
+    pub async fn check_balance(&self) -> PaystackResult<Vec<BalanceData>> {
+        let url = format!("{}/balance", self.base_url);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransferControl, e))?;
+
+        let parsed_response: Response<Vec<BalanceData>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::TransferControl(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Fetch all pay-ins and pay-outs that occured on your integration
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the ledger entries returned.
+    ///   It should be created with the `ListBalanceLedgerFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of balance ledger entries or an error
+    pub async fn fetch_balance_ledger(
+        &self,
+        filter: Option<ListBalanceLedgerFilter>,
+    ) -> PaystackResult<Vec<BalanceLedgerData>> {
+        let url = format!("{}/balance/ledger", self.base_url);
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(from) = filter.from {
+            query.push(("from", from));
+        }
+        if let Some(to) = filter.to {
+            query.push(("to", to));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(&url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransferControl, e))?;
+
+        let parsed_response: Response<Vec<BalanceLedgerData>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::TransferControl(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Generates a new OTP and sends to customer in the event they are having trouble receiving one.
+    ///
+    /// # Arguments
+    /// * `transfer_code` - Transfer code
+    /// * `reason` - The reason for resending the OTP
+    ///
+    /// # Returns
+    /// A Result containing a success message if the OTP has been resent
+    pub async fn resend_transfer_otp(
+        &self,
+        transfer_code: String,
+        reason: ResendOtpReason,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/transfer/resend_otp", self.base_url);
+        let body = json!({
+            "transfer_code": transfer_code,
+            "reason": reason
+        });
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransferControl, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::TransferControl(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// This is used in the event that you want to be able to complete transfers programmatically without use of OTPs.
+    /// No arguments required. You will get an OTP to complete the request.
+    ///
+    /// # Returns
+    /// A Result containing a success message if the OTP has been sent
+    pub async fn disable_otp(&self) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/transfer/disable_otp", self.base_url);
+        let body = json!({});
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransferControl, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::TransferControl(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Finalize the request to disable OTP on your transfers.
+    ///
+    /// # Arguments
+    /// * `otp` - OTP sent to business phone to verify disabling OTP requirement
+    ///
+    /// # Returns
+    /// A Result containing a success message if the OTP requirement has been disabled
+    pub async fn finalize_disable_otp(&self, otp: String) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/transfer/disable_otp_finalize", self.base_url);
+        let body = json!({
+            "otp": otp
+        });
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransferControl, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::TransferControl(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// In the event that a customer wants to stop being able to complete transfers programmatically,
+    /// this endpoint helps turn OTP requirement back on. No arguments required.
+    ///
+    /// # Returns
+    /// A Result containing a success message if the OTP requirement has been enabled
+    pub async fn enable_otp(&self) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/transfer/enable_otp", self.base_url);
+        let body = json!({});
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransferControl, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::TransferControl(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
@@ -0,0 +1,246 @@
+//! Transfer Recipients
+//! ===================
+//! The Transfer Recipients API allows you to create and manage beneficiaries that you send money to.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    BulkTransferRecipientResponseData, HttpClient, ListTransferRecipientsFilter, PaystackAPIError,
+    PaystackResult, Response, TransferRecipientRequest, TransferRecipientResponseData,
+};
+use serde_json::json;
+use std::{marker::PhantomData, sync::Arc};
+
+/// A struct to hold all the functions of the transfer recipients API endpoint
+#[derive(Debug, Clone)]
+pub struct TransferRecipientEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the transfer recipient route
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> TransferRecipientEndpoints<T> {
+    /// Creates a new TransferRecipientEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new TransferRecipientEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> TransferRecipientEndpoints<T> {
+        TransferRecipientEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new TransferRecipientEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new TransferRecipientEndpoints instance
+    pub fn with_base_url(
+        key: Arc<String>,
+        http: Arc<T>,
+        base_url: &str,
+    ) -> TransferRecipientEndpoints<T> {
+        let base_url = format!("{base_url}/transferrecipient");
+        TransferRecipientEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Creates a new recipient. A duplicate account number will lead to the retrieval of the existing record.
+    ///
+    /// # Arguments
+    /// * `recipient_request` - The request data to create the transfer recipient.
+    ///   Should be created with a `TransferRecipientRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the transfer recipient response data or an error
+    pub async fn create_transfer_recipient(
+        &self,
+        recipient_request: TransferRecipientRequest,
+    ) -> PaystackResult<TransferRecipientResponseData> {
+        let url = &self.base_url;
+        let body = serde_json::to_value(recipient_request)
+            .map_err(|e| PaystackAPIError::TransferRecipient(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransferRecipient, e))?;
+
+        let parsed_response: Response<TransferRecipientResponseData> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::TransferRecipient(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Create multiple transfer recipients in batches. A duplicate account number will lead to the retrieval of the existing record.
+    ///
+    /// # Arguments
+    /// * `batch` - A list of transfer recipients to create.
+    ///   Each should be created with a `TransferRecipientRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the created recipients and the errors encountered, or an error
+    pub async fn bulk_create_transfer_recipient(
+        &self,
+        batch: Vec<TransferRecipientRequest>,
+    ) -> PaystackResult<BulkTransferRecipientResponseData> {
+        let url = format!("{}/bulk", self.base_url);
+        let body = json!({
+            "batch": batch
+        });
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransferRecipient, e))?;
+
+        let parsed_response: Response<BulkTransferRecipientResponseData> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::TransferRecipient(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// List transfer recipients available on your integration
+    ///
+    /// # Arguments
+    /// * `filter` - Optional set of parameters to filter the transfer recipients returned.
+    ///   It should be created with the `ListTransferRecipientsFilterBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing a vector of transfer recipient response data or an error
+    pub async fn list_transfer_recipients(
+        &self,
+        filter: Option<ListTransferRecipientsFilter>,
+    ) -> PaystackResult<Vec<TransferRecipientResponseData>> {
+        let url = &self.base_url;
+        let filter = filter.unwrap_or_default();
+
+        let per_page = filter.per_page.unwrap_or(50).to_string();
+        let page = filter.page.unwrap_or(1).to_string();
+        let mut query = vec![("perPage", per_page), ("page", page)];
+
+        if let Some(from) = filter.from {
+            query.push(("from", from));
+        }
+        if let Some(to) = filter.to {
+            query.push(("to", to));
+        }
+
+        // Transform String to &str using iter
+        let query: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = self
+            .http
+            .get(url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransferRecipient, e))?;
+
+        let parsed_response: Response<Vec<TransferRecipientResponseData>> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::TransferRecipient(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Fetch the details of a transfer recipient
+    ///
+    /// # Arguments
+    /// * `id_or_code` - An ID or code for the recipient whose details you want to receive.
+    ///
+    /// # Returns
+    /// A Result containing the transfer recipient response data or an error
+    pub async fn fetch_transfer_recipient(
+        &self,
+        id_or_code: String,
+    ) -> PaystackResult<TransferRecipientResponseData> {
+        let url = format!("{}/{}", self.base_url, id_or_code);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransferRecipient, e))?;
+
+        let parsed_response: Response<TransferRecipientResponseData> =
+            serde_json::from_str(&response)
+                .map_err(|e| PaystackAPIError::TransferRecipient(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Update the details of a transfer recipient
+    ///
+    /// # Arguments
+    /// * `id_or_code` - Transfer Recipient's ID or code
+    /// * `name` - A name for the recipient
+    /// * `email` - Optional email address of the recipient
+    ///
+    /// # Returns
+    /// A Result containing a success message if the recipient has been updated
+    pub async fn update_transfer_recipient(
+        &self,
+        id_or_code: String,
+        name: String,
+        email: Option<String>,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/{}", self.base_url, id_or_code);
+        let mut body = json!({
+            "name": name
+        });
+        if let Some(email) = email {
+            body["email"] = json!(email);
+        }
+
+        let response = self
+            .http
+            .put(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransferRecipient, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::TransferRecipient(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Delete a transfer recipient (sets the transfer recipient to inactive)
+    ///
+    /// # Arguments
+    /// * `id_or_code` - An ID or code for the recipient who you want to delete.
+    ///
+    /// # Returns
+    /// A Result containing a success message if the recipient has been deleted
+    pub async fn delete_transfer_recipient(
+        &self,
+        id_or_code: String,
+    ) -> PaystackResult<PhantomData<String>> {
+        let url = format!("{}/{}", self.base_url, id_or_code);
+        let body = json!({});
+
+        let response = self
+            .http
+            .delete(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::TransferRecipient, e))?;
+
+        let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::TransferRecipient(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
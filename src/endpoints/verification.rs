@@ -0,0 +1,140 @@
+//! Verification
+//! ============
+//! The Verification API allows you to perform KYC processes, such as resolving a bank account
+//! before creating a subaccount or transfer recipient.
+
+use super::PAYSTACK_BASE_URL;
+use crate::{
+    CardBinData, HttpClient, PaystackAPIError, PaystackResult, ResolveAccountData, Response,
+    ValidateAccountData, ValidateAccountRequest,
+};
+use std::sync::Arc;
+
+/// A struct to hold all the functions of the verification API endpoint
+#[derive(Debug, Clone)]
+pub struct VerificationEndpoints<T: HttpClient + Default> {
+    /// Paystack API Key
+    key: String,
+    /// Base URL for the verification routes
+    base_url: String,
+    /// Http client for the route
+    http: Arc<T>,
+}
+
+impl<T: HttpClient + Default> VerificationEndpoints<T> {
+    /// Creates a new VerificationEndpoints instance
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    ///
+    /// # Returns
+    /// A new VerificationEndpoints instance
+    pub fn new(key: Arc<String>, http: Arc<T>) -> VerificationEndpoints<T> {
+        VerificationEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new VerificationEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new VerificationEndpoints instance
+    pub fn with_base_url(
+        key: Arc<String>,
+        http: Arc<T>,
+        base_url: &str,
+    ) -> VerificationEndpoints<T> {
+        let base_url = base_url.to_string();
+        VerificationEndpoints {
+            key: key.to_string(),
+            base_url,
+            http,
+        }
+    }
+
+    /// Confirm an account belongs to the right customer
+    ///
+    /// # Arguments
+    /// * `account_number` - Account number
+    /// * `bank_code` - You can get the list of bank codes by calling the List Banks endpoint
+    ///
+    /// # Returns
+    /// A Result containing the resolved account data or an error
+    pub async fn resolve_account_number(
+        &self,
+        account_number: String,
+        bank_code: String,
+    ) -> PaystackResult<ResolveAccountData> {
+        let url = format!("{}/bank/resolve", self.base_url);
+        let query = vec![
+            ("account_number", account_number.as_str()),
+            ("bank_code", bank_code.as_str()),
+        ];
+
+        let response = self
+            .http
+            .get(&url, &self.key, Some(&query))
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Verification, e))?;
+
+        let parsed_response: Response<ResolveAccountData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Verification(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Confirm the authenticity of a customer's account number before sending money.
+    /// This is currently only supported for South African accounts.
+    ///
+    /// # Arguments
+    /// * `validate_request` - The account details to validate.
+    ///   Should be created with a `ValidateAccountRequestBuilder` struct.
+    ///
+    /// # Returns
+    /// A Result containing the validation result or an error
+    pub async fn validate_account(
+        &self,
+        validate_request: ValidateAccountRequest,
+    ) -> PaystackResult<ValidateAccountData> {
+        let url = format!("{}/bank/validate", self.base_url);
+        let body = serde_json::to_value(validate_request)
+            .map_err(|e| PaystackAPIError::Verification(e.to_string()))?;
+
+        let response = self
+            .http
+            .post(&url, &self.key, &body)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Verification, e))?;
+
+        let parsed_response: Response<ValidateAccountData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Verification(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+
+    /// Get more information about a customer's card
+    ///
+    /// # Arguments
+    /// * `bin` - First 6 characters of card
+    ///
+    /// # Returns
+    /// A Result containing the card BIN data or an error
+    pub async fn resolve_card_bin(&self, bin: String) -> PaystackResult<CardBinData> {
+        let url = format!("{}/decision/bin/{}", self.base_url, bin);
+
+        let response = self
+            .http
+            .get(&url, &self.key, None)
+            .await
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::Verification, e))?;
+
+        let parsed_response: Response<CardBinData> = serde_json::from_str(&response)
+            .map_err(|e| PaystackAPIError::Verification(e.to_string()))?;
+
+        Ok(parsed_response)
+    }
+}
@@ -31,7 +31,24 @@ impl<T: HttpClient + Default> VirtualTerminalEndpoints<T> {
     /// # Returns
     /// A new VirtualTerminalEndpoints instance
     pub fn new(key: Arc<String>, http: Arc<T>) -> VirtualTerminalEndpoints<T> {
-        let base_url = format!("{PAYSTACK_BASE_URL}/virtual_terminal");
+        VirtualTerminalEndpoints::with_base_url(key, http, PAYSTACK_BASE_URL)
+    }
+
+    /// Creates a new VirtualTerminalEndpoints instance that sends its requests to the given base URL
+    ///
+    /// # Arguments
+    /// * `key` - The Paystack API key
+    /// * `http` - The HTTP client implementation to use for API requests
+    /// * `base_url` - The base URL of the Paystack API e.g. `https://api.paystack.co`
+    ///
+    /// # Returns
+    /// A new VirtualTerminalEndpoints instance
+    pub fn with_base_url(
+        key: Arc<String>,
+        http: Arc<T>,
+        base_url: &str,
+    ) -> VirtualTerminalEndpoints<T> {
+        let base_url = format!("{base_url}/virtual_terminal");
         VirtualTerminalEndpoints {
             key: key.to_string(),
             base_url,
@@ -59,7 +76,7 @@ impl<T: HttpClient + Default> VirtualTerminalEndpoints<T> {
             .http
             .post(url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::VirtualTerminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::VirtualTerminal, e))?;
 
         let parsed_response: Response<VirtualTerminalResponseData> =
             serde_json::from_str(&response)
@@ -91,7 +108,7 @@ impl<T: HttpClient + Default> VirtualTerminalEndpoints<T> {
             .http
             .get(url, &self.key, Some(&query))
             .await
-            .map_err(|e| PaystackAPIError::VirtualTerminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::VirtualTerminal, e))?;
 
         let parsed_response: Response<Vec<VirtualTerminalResponseData>> =
             serde_json::from_str(&response)
@@ -117,7 +134,7 @@ impl<T: HttpClient + Default> VirtualTerminalEndpoints<T> {
             .http
             .get(&url, &self.key, None)
             .await
-            .map_err(|e| PaystackAPIError::VirtualTerminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::VirtualTerminal, e))?;
 
         let parsed_response: Response<VirtualTerminalResponseData> =
             serde_json::from_str(&response)
@@ -148,7 +165,7 @@ impl<T: HttpClient + Default> VirtualTerminalEndpoints<T> {
             .http
             .put(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::VirtualTerminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::VirtualTerminal, e))?;
 
         let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::VirtualTerminal(e.to_string()))?;
@@ -174,7 +191,7 @@ impl<T: HttpClient + Default> VirtualTerminalEndpoints<T> {
             .http
             .put(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::VirtualTerminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::VirtualTerminal, e))?;
 
         let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::VirtualTerminal(e.to_string()))?;
@@ -204,7 +221,7 @@ impl<T: HttpClient + Default> VirtualTerminalEndpoints<T> {
             .http
             .post(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::VirtualTerminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::VirtualTerminal, e))?;
 
         let parsed_response: Response<Vec<DestinationResponse>> =
             serde_json::from_str(&response)
@@ -235,7 +252,7 @@ impl<T: HttpClient + Default> VirtualTerminalEndpoints<T> {
             .http
             .post(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::VirtualTerminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::VirtualTerminal, e))?;
 
         let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::VirtualTerminal(e.to_string()))?;
@@ -265,7 +282,7 @@ impl<T: HttpClient + Default> VirtualTerminalEndpoints<T> {
             .http
             .put(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::VirtualTerminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::VirtualTerminal, e))?;
 
         let parsed_response: Response<TransactionSplitResponseData> =
             serde_json::from_str(&response)
@@ -296,7 +313,7 @@ impl<T: HttpClient + Default> VirtualTerminalEndpoints<T> {
             .http
             .delete(&url, &self.key, &body)
             .await
-            .map_err(|e| PaystackAPIError::VirtualTerminal(e.to_string()))?;
+            .map_err(|e| PaystackAPIError::from_http(PaystackAPIError::VirtualTerminal, e))?;
 
         let parsed_response: Response<PhantomData<String>> = serde_json::from_str(&response)
             .map_err(|e| PaystackAPIError::VirtualTerminal(e.to_string()))?;
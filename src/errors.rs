@@ -1,6 +1,9 @@
 //! Error
 //! ========
 //! This file contains the structs and definitions of the errors in this crate.
+use crate::HttpError;
+use serde_json::Value;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Custom Error for the Paystack API
@@ -37,4 +40,339 @@ pub enum PaystackAPIError {
     ApplePay(String),
     #[error("Plan Error: {0}")]
     Plan(String),
+    /// Error associated with transfers
+    #[error("Transfer Error: {0}")]
+    Transfer(String),
+    /// Error associated with transfer recipients
+    #[error("Transfer Recipient Error: {0}")]
+    TransferRecipient(String),
+    /// Error associated with transfers control
+    #[error("Transfer Control Error: {0}")]
+    TransferControl(String),
+    /// Error associated with refunds
+    #[error("Refund Error: {0}")]
+    Refund(String),
+    /// Error associated with disputes
+    #[error("Dispute Error: {0}")]
+    Dispute(String),
+    /// Error associated with subscriptions
+    #[error("Subscription Error: {0}")]
+    Subscription(String),
+    /// Error associated with bulk charges
+    #[error("Bulk Charge Error: {0}")]
+    BulkCharge(String),
+    /// Error associated with payment requests
+    #[error("Payment Request Error: {0}")]
+    PaymentRequest(String),
+    /// Error associated with products
+    #[error("Product Error: {0}")]
+    Product(String),
+    /// Error associated with payment pages
+    #[error("Payment Page Error: {0}")]
+    PaymentPage(String),
+    /// Error associated with settlements
+    #[error("Settlement Error: {0}")]
+    Settlement(String),
+    /// Error associated with verification
+    #[error("Verification Error: {0}")]
+    Verification(String),
+    /// Error associated with miscellaneous endpoints
+    #[error("Miscellaneous Error: {0}")]
+    Miscellaneous(String),
+    /// Error associated with integration settings
+    #[error("Integration Error: {0}")]
+    Integration(String),
+    /// Error associated with direct debit
+    #[error("Direct Debit Error: {0}")]
+    DirectDebit(String),
+    /// Error associated with storefronts
+    #[error("Storefront Error: {0}")]
+    Storefront(String),
+    /// Error associated with orders
+    #[error("Order Error: {0}")]
+    Order(String),
+    /// Error associated with webhook verification and parsing
+    #[error("Webhook Error: {0}")]
+    Webhook(String),
+    /// Error response returned by the Paystack API
+    #[error(transparent)]
+    Api(Box<ApiError>),
+    /// The request failed before a response was received from the Paystack API
+    #[error("{message}")]
+    Transport {
+        /// Description of the failure, prefixed with the route of the request
+        message: String,
+        /// Whether the failure may not happen again if the request is retried,
+        /// e.g. a timeout or a failed connection
+        transient: bool,
+    },
+}
+
+impl PaystackAPIError {
+    /// Maps an error from the HTTP client to the error of a route.
+    /// Error responses from the Paystack API are kept as a structured `ApiError`,
+    /// and other failures become a `Transport` error described by the route.
+    ///
+    /// # Arguments
+    /// * `route` - The error variant of the route, e.g. `PaystackAPIError::Transaction`
+    /// * `error` - The error returned by the HTTP client
+    ///
+    /// # Returns
+    /// The mapped error
+    pub fn from_http<E: HttpError>(route: fn(String) -> Self, error: E) -> Self {
+        match error.api_error() {
+            Some(api_error) => PaystackAPIError::Api(Box::new(api_error.clone())),
+            None => PaystackAPIError::Transport {
+                message: route(error.to_string()).to_string(),
+                transient: error.is_transient(),
+            },
+        }
+    }
+
+    /// The error response returned by the Paystack API, if there is one
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            PaystackAPIError::Api(api_error) => Some(api_error),
+            _ => None,
+        }
+    }
+
+    /// Checks if the request failed in a way that may succeed if retried.
+    /// This matches the errors a `RetryClient` retries.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            PaystackAPIError::Transport { transient, .. } => *transient,
+            error => error.api_error().is_some_and(ApiError::is_retryable),
+        }
+    }
+
+    /// Checks if the request was rejected because of the API key
+    pub fn is_auth_error(&self) -> bool {
+        self.api_error().is_some_and(ApiError::is_auth_error)
+    }
+
+    /// Checks if the requested resource does not exist
+    pub fn is_not_found(&self) -> bool {
+        self.api_error().is_some_and(ApiError::is_not_found)
+    }
+}
+
+/// Error response returned by the Paystack API for a request with a non 2xx status code.
+#[derive(Error, Debug, Clone, Default, PartialEq)]
+#[error("Paystack API Error ({status}): {message}")]
+pub struct ApiError {
+    /// HTTP status code of the response
+    pub status: u16,
+    /// Summary of the error
+    pub message: String,
+    /// Paystack error code e.g. `invalid_params`
+    pub code: Option<String>,
+    /// Paystack error type e.g. `validation_error`
+    pub error_type: Option<String>,
+    /// Suggestion on how to resolve the error, from the `meta.nextStep` field
+    pub next_step: Option<String>,
+    /// Errors of the individual fields of the request
+    pub validation_errors: Vec<ValidationError>,
+    /// How long to wait before retrying the request, from the `Retry-After` header
+    pub retry_after: Option<Duration>,
+}
+
+/// Error of a single field in a request rejected by the Paystack API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationError {
+    /// The request field the error is for, if Paystack reports it
+    pub field: Option<String>,
+    /// The validation rule that failed e.g. `required`
+    pub rule: Option<String>,
+    /// Description of the error
+    pub message: String,
+}
+
+impl ApiError {
+    /// Creates an ApiError from the status code and body of an error response.
+    /// Bodies that are not JSON are used as the message.
+    ///
+    /// # Arguments
+    /// * `status` - The HTTP status code of the response
+    /// * `body` - The body of the response
+    ///
+    /// # Returns
+    /// A new ApiError
+    pub fn from_response(status: u16, body: &str) -> Self {
+        let Ok(body) = serde_json::from_str::<Value>(body) else {
+            return ApiError {
+                status,
+                message: body.trim().to_string(),
+                ..Default::default()
+            };
+        };
+        let text = |value: &Value| value.as_str().map(str::to_string);
+
+        ApiError {
+            status,
+            message: text(&body["message"]).unwrap_or_default(),
+            code: text(&body["code"]),
+            error_type: text(&body["type"]),
+            next_step: text(&body["meta"]["nextStep"]),
+            validation_errors: ValidationError::from_value(&body["errors"]),
+            retry_after: None,
+        }
+    }
+
+    /// Checks if the request failed in a way that may succeed if retried.
+    /// This is the case for timeouts, rate limiting and server errors.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self.status, 408 | 429 | 500..=599)
+    }
+
+    /// Checks if the request was rejected because of the API key
+    pub fn is_auth_error(&self) -> bool {
+        matches!(self.status, 401 | 403)
+    }
+
+    /// Checks if the requested resource does not exist
+    pub fn is_not_found(&self) -> bool {
+        self.status == 404
+    }
+}
+
+impl ValidationError {
+    /// Parses the `errors` field of an error response, which is either a list of errors
+    /// or an object of errors keyed by field.
+    fn from_value(errors: &Value) -> Vec<Self> {
+        match errors {
+            Value::Array(errors) => errors.iter().map(|e| Self::parse(None, e)).collect(),
+            Value::Object(fields) => fields
+                .iter()
+                .flat_map(|(field, errors)| match errors {
+                    Value::Array(errors) => errors
+                        .iter()
+                        .map(|e| Self::parse(Some(field.clone()), e))
+                        .collect(),
+                    error => vec![Self::parse(Some(field.clone()), error)],
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    fn parse(field: Option<String>, error: &Value) -> Self {
+        let text = |value: &Value| value.as_str().map(str::to_string);
+        match error {
+            Value::String(message) => ValidationError {
+                field,
+                rule: None,
+                message: message.clone(),
+            },
+            error => ValidationError {
+                field: field.or_else(|| text(&error["field"])),
+                rule: text(&error["rule"]),
+                message: text(&error["message"]).unwrap_or_else(|| error.to_string()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::fake::FakeError;
+    use crate::ReqwestError;
+
+    #[test]
+    fn can_parse_api_error_response() {
+        let body = r#"{
+            "status": false,
+            "message": "Invalid key",
+            "meta": {"nextStep": "Ensure that you provide the correct authorization key"},
+            "type": "validation_error",
+            "code": "invalid_Key"
+        }"#;
+
+        let error = ApiError::from_response(401, body);
+
+        assert_eq!(error.message, "Invalid key");
+        assert_eq!(error.code.as_deref(), Some("invalid_Key"));
+        assert_eq!(error.error_type.as_deref(), Some("validation_error"));
+        assert!(error.next_step.is_some());
+        assert!(error.is_auth_error());
+        assert!(!error.is_retryable());
+    }
+
+    #[test]
+    fn can_parse_validation_errors() {
+        let body = r#"{
+            "status": false,
+            "message": "Invalid params",
+            "errors": {"email": [{"rule": "required", "message": "Email is required"}]}
+        }"#;
+
+        let error = ApiError::from_response(400, body);
+
+        assert_eq!(
+            error.validation_errors,
+            vec![ValidationError {
+                field: Some("email".to_string()),
+                rule: Some("required".to_string()),
+                message: "Email is required".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn http_errors_keep_the_api_error() {
+        let api_error = ApiError::from_response(503, "Service Unavailable");
+        let error = PaystackAPIError::from_http(
+            PaystackAPIError::Transaction,
+            ReqwestError::Api(Box::new(api_error)),
+        );
+        assert!(error.is_retryable());
+        assert_eq!(error.api_error().unwrap().message, "Service Unavailable");
+
+        let error =
+            PaystackAPIError::from_http(PaystackAPIError::Transaction, "timed out".to_string());
+        assert!(matches!(
+            error,
+            PaystackAPIError::Transport {
+                transient: false,
+                ..
+            }
+        ));
+        assert_eq!(error.to_string(), "Transaction Error: timed out");
+        assert!(!error.is_not_found());
+    }
+
+    #[test]
+    fn transient_transport_errors_are_retryable() {
+        let error =
+            PaystackAPIError::from_http(PaystackAPIError::Transaction, FakeError::transport(true));
+        assert!(error.is_retryable());
+        assert!(error.api_error().is_none());
+
+        let error =
+            PaystackAPIError::from_http(PaystackAPIError::Transaction, FakeError::transport(false));
+        assert!(!error.is_retryable());
+    }
+
+    /// Error of a custom HTTP client that keeps the API error responses
+    #[derive(Debug, Error)]
+    #[error("custom client error: {0}")]
+    struct CustomClientError(ApiError);
+
+    impl HttpError for CustomClientError {
+        fn api_error(&self) -> Option<&ApiError> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn custom_http_errors_keep_the_api_error() {
+        let api_error = ApiError::from_response(404, r#"{"message": "Customer not found"}"#);
+
+        let error =
+            PaystackAPIError::from_http(PaystackAPIError::Customer, CustomClientError(api_error));
+
+        assert!(error.is_not_found());
+        assert_eq!(error.api_error().unwrap().message, "Customer not found");
+    }
 }
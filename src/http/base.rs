@@ -1,3 +1,4 @@
+use crate::ApiError;
 use async_trait::async_trait;
 use serde_json::Value;
 use std::fmt::{Debug, Display};
@@ -5,6 +6,31 @@ use std::fmt::{Debug, Display};
 /// A predefined type for the query type in the HTTP client.
 pub type Query<'a> = Vec<(&'a str, &'a str)>;
 
+/// An error returned by an HTTP client.
+///
+/// Implement `api_error` for the error responses of the Paystack API to be kept as a
+/// structured `ApiError`, and `is_transient` for failed requests to be retried by a `RetryClient`.
+pub trait HttpError: Debug + Display {
+    /// The error response returned by the Paystack API, if the request reached it
+    fn api_error(&self) -> Option<&ApiError> {
+        None
+    }
+
+    /// Checks if the request failed before reaching the API in a way that may succeed
+    /// if retried, e.g. a timeout or a failed connection
+    fn is_transient(&self) -> bool {
+        false
+    }
+}
+
+impl HttpError for String {}
+
+impl HttpError for ApiError {
+    fn api_error(&self) -> Option<&ApiError> {
+        Some(self)
+    }
+}
+
 /// This trait is a collection of the stand HTTP methods for any client.
 /// The aim of the trait is to abstract ways the HTTP implementation found in
 /// different HTTP clients.
@@ -17,7 +43,7 @@ pub type Query<'a> = Vec<(&'a str, &'a str)>;
 #[async_trait]
 pub trait HttpClient: Debug + Default + Clone + Send {
     /// HTTP error
-    type Error: Debug + Display;
+    type Error: HttpError;
 
     /// Send http get request
     async fn get(
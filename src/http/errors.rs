@@ -1,3 +1,5 @@
+use super::base::HttpError;
+use crate::ApiError;
 use thiserror::Error;
 
 /// An error enum to hold errors from reqwest client
@@ -10,7 +12,23 @@ pub enum ReqwestError {
 
     /// The initial request was successful, but the status code is in the 400
     /// and 500 range. This signifies that API cannot handle the request sent,
-    /// We are only interested in the status code of this error
-    #[error("status code: {}", reqwest::Response::status(.0))]
-    StatusCode(reqwest::Response),
+    /// the error response from the API is kept in the error.
+    #[error("{0}")]
+    Api(Box<ApiError>),
+}
+
+impl HttpError for ReqwestError {
+    fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            ReqwestError::Api(api_error) => Some(api_error),
+            ReqwestError::Reqwest(_) => None,
+        }
+    }
+
+    fn is_transient(&self) -> bool {
+        match self {
+            ReqwestError::Reqwest(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+            ReqwestError::Api(_) => false,
+        }
+    }
 }
@@ -0,0 +1,186 @@
+//! Fake
+//! ====
+//! This file contains a fake HTTP client shared by the unit tests of the crate.
+//!
+//! The client records every request it receives and answers them with a responder
+//! function, so tests can serve canned responses and check what was sent.
+
+use super::base::{HttpError, Query};
+use crate::{ApiError, HttpClient};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+/// A request received by the fake client
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FakeRequest {
+    /// HTTP method of the request e.g. `GET`
+    pub method: &'static str,
+    /// URL of the request
+    pub url: String,
+    /// Query parameters of a GET request
+    pub query: Vec<(String, String)>,
+    /// Body of the request, `Null` for GET requests
+    pub body: Value,
+}
+
+impl FakeRequest {
+    /// Value of a query parameter of the request
+    pub fn param(&self, key: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Error returned by the fake client
+#[derive(Debug, Clone)]
+pub(crate) enum FakeError {
+    /// An error response of the Paystack API
+    Api(Box<ApiError>),
+    /// A request that failed before reaching the API
+    Transport { message: String, transient: bool },
+}
+
+impl FakeError {
+    /// An error response of the Paystack API with the given status and body
+    pub fn api(status: u16, body: &str) -> Self {
+        FakeError::Api(Box::new(ApiError::from_response(status, body)))
+    }
+
+    /// A request that failed before reaching the API
+    pub fn transport(transient: bool) -> Self {
+        FakeError::Transport {
+            message: "connection reset".to_string(),
+            transient,
+        }
+    }
+}
+
+impl fmt::Display for FakeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FakeError::Api(api_error) => write!(f, "{api_error}"),
+            FakeError::Transport { message, .. } => write!(f, "{message}"),
+        }
+    }
+}
+
+impl HttpError for FakeError {
+    fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            FakeError::Api(api_error) => Some(api_error),
+            FakeError::Transport { .. } => None,
+        }
+    }
+
+    fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            FakeError::Transport {
+                transient: true,
+                ..
+            }
+        )
+    }
+}
+
+type Responder = dyn Fn(&FakeRequest) -> Result<String, FakeError> + Send + Sync;
+
+/// HTTP client that records its requests and answers them with a responder function.
+/// Clones share the recorded requests.
+#[derive(Clone)]
+pub(crate) struct FakeClient {
+    responder: Arc<Responder>,
+    requests: Arc<Mutex<Vec<FakeRequest>>>,
+}
+
+impl FakeClient {
+    /// Creates a fake client answering every request with the responder
+    pub fn new(
+        responder: impl Fn(&FakeRequest) -> Result<String, FakeError> + Send + Sync + 'static,
+    ) -> Self {
+        FakeClient {
+            responder: Arc::new(responder),
+            requests: Arc::default(),
+        }
+    }
+
+    /// Creates a fake client answering every request with the given body
+    pub fn ok(body: impl Into<String>) -> Self {
+        let body = body.into();
+        FakeClient::new(move |_| Ok(body.clone()))
+    }
+
+    /// The requests received so far, in order
+    pub fn requests(&self) -> Vec<FakeRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn send(
+        &self,
+        method: &'static str,
+        url: &str,
+        query: Option<&Query>,
+        body: &Value,
+    ) -> Result<String, FakeError> {
+        let request = FakeRequest {
+            method,
+            url: url.to_string(),
+            query: query
+                .into_iter()
+                .flatten()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            body: body.clone(),
+        };
+        let response = (self.responder)(&request);
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(request);
+        response
+    }
+}
+
+impl Default for FakeClient {
+    fn default() -> Self {
+        FakeClient::ok(r#"{"status": true, "message": "ok"}"#)
+    }
+}
+
+impl fmt::Debug for FakeClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FakeClient")
+            .field("requests", &self.requests)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl HttpClient for FakeClient {
+    type Error = FakeError;
+
+    async fn get(&self, url: &str, _: &str, query: Option<&Query>) -> Result<String, FakeError> {
+        self.send("GET", url, query, &Value::Null)
+    }
+
+    async fn post(&self, url: &str, _: &str, body: &Value) -> Result<String, FakeError> {
+        self.send("POST", url, None, body)
+    }
+
+    async fn put(&self, url: &str, _: &str, body: &Value) -> Result<String, FakeError> {
+        self.send("PUT", url, None, body)
+    }
+
+    async fn delete(&self, url: &str, _: &str, body: &Value) -> Result<String, FakeError> {
+        self.send("DELETE", url, None, body)
+    }
+}
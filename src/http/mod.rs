@@ -4,9 +4,15 @@
 
 pub mod base;
 pub mod errors;
+#[cfg(test)]
+pub(crate) mod fake;
+pub mod rate_limit;
 pub mod reqwest;
+pub mod retry;
 
 // public re-export
-pub use base::HttpClient;
+pub use base::{HttpClient, HttpError};
 pub use errors::ReqwestError;
+pub use rate_limit::{RateLimit, RateLimitedClient, RateLimiter};
 pub use reqwest::ReqwestClient;
+pub use retry::{RetryClient, RetryPolicy, RetryPolicyBuilder};
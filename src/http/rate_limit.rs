@@ -0,0 +1,441 @@
+//! Rate Limiting
+//! =============
+//! This file contains a token bucket rate limiter and an HTTP client that applies it to its requests.
+//!
+//! The limiter lives inside the HTTP client, so it is shared by every endpoint group of a
+//! `PaystackClient` through the same `Arc` that shares the HTTP client.
+
+use crate::http::base::Query;
+use crate::{HttpClient, PaystackAPIError, PaystackClient, PAYSTACK_BASE_URL};
+use async_trait::async_trait;
+use reqwest::Url;
+use serde_json::Value;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::Instant;
+
+/// The rate of a token bucket.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// Maximum number of requests that can be sent at once
+    burst: u32,
+    /// Number of requests allowed per second once the burst is used up
+    per_second: f64,
+}
+
+impl RateLimit {
+    /// Creates a new RateLimit instance
+    ///
+    /// # Arguments
+    /// * `burst` - Maximum number of requests that can be sent at once
+    /// * `per_second` - Number of requests allowed per second once the burst is used up
+    ///
+    /// # Returns
+    /// A new RateLimit instance, or an error if `per_second` is not a positive, finite number
+    /// or is too small for the wait between two requests to fit in a `Duration`
+    pub fn new(burst: u32, per_second: f64) -> Result<Self, PaystackAPIError> {
+        if !per_second.is_finite() || Duration::try_from_secs_f64(1.0 / per_second).is_err() {
+            return Err(PaystackAPIError::Generic(format!(
+                "rate limit must allow a positive, finite number of requests per second, got {per_second}"
+            )));
+        }
+        Ok(RateLimit { burst, per_second })
+    }
+
+    /// Creates a RateLimit allowing `requests` requests per second, with a burst of the same size.
+    /// Returns an error if `requests` is 0.
+    pub fn per_second(requests: u32) -> Result<Self, PaystackAPIError> {
+        RateLimit::new(requests, requests as f64)
+    }
+
+    /// Creates a RateLimit allowing `requests` requests per minute, with a burst of one request.
+    /// Returns an error if `requests` is 0.
+    pub fn per_minute(requests: u32) -> Result<Self, PaystackAPIError> {
+        RateLimit::new(1, requests as f64 / 60.0)
+    }
+
+    /// Maximum number of requests that can be sent at once
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Number of requests allowed per second once the burst is used up
+    pub fn rate(&self) -> f64 {
+        self.per_second
+    }
+}
+
+/// A token bucket refilled at a constant rate
+#[derive(Debug)]
+struct TokenBucket {
+    limit: RateLimit,
+    /// Available tokens and the last time they were refilled
+    state: Mutex<(f64, Instant)>,
+}
+
+impl TokenBucket {
+    fn new(limit: RateLimit) -> Self {
+        let tokens = limit.burst.max(1) as f64;
+        TokenBucket {
+            limit,
+            state: Mutex::new((tokens, Instant::now())),
+        }
+    }
+
+    /// Waits until a token is available and takes it
+    async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+                let (tokens, last) = &mut *state;
+                let now = Instant::now();
+
+                let capacity = self.limit.burst.max(1) as f64;
+                let refilled = now.duration_since(*last).as_secs_f64() * self.limit.per_second;
+                *tokens = (*tokens + refilled).min(capacity);
+                *last = now;
+
+                if *tokens >= 1.0 {
+                    *tokens -= 1.0;
+                    return;
+                }
+                (1.0 - *tokens) / self.limit.per_second
+            };
+            // `RateLimit::new` ensures the wait for a whole token fits in a Duration
+            tokio::time::sleep(Duration::from_secs_f64(wait)).await;
+        }
+    }
+}
+
+/// A token bucket rate limiter, with a limit for all requests and optional stricter limits per route.
+///
+/// A request to a route with its own limit takes a token from the route bucket
+/// and from the bucket shared by all requests.
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// Base URL the routes are relative to
+    base_url: String,
+    /// Bucket shared by all requests
+    global: TokenBucket,
+    /// Buckets of the routes with their own limit
+    routes: Vec<RouteLimit>,
+}
+
+/// The limit of a route
+#[derive(Debug)]
+struct RouteLimit {
+    /// HTTP method of the route, `None` for every method
+    method: Option<String>,
+    /// Path of the route relative to the base URL
+    path: String,
+    bucket: TokenBucket,
+}
+
+impl RouteLimit {
+    fn matches(&self, method: &str, path: &str) -> bool {
+        let method_matches = self
+            .method
+            .as_deref()
+            .is_none_or(|route_method| route_method.eq_ignore_ascii_case(method));
+        let path_matches = match self.path.strip_suffix("/*") {
+            Some(prefix) => path.starts_with(&format!("{prefix}/")),
+            None => path == self.path.trim_end_matches('/'),
+        };
+        method_matches && path_matches
+    }
+}
+
+impl RateLimiter {
+    /// Creates a new RateLimiter instance
+    ///
+    /// # Arguments
+    /// * `limit` - The limit applied to all requests
+    ///
+    /// # Returns
+    /// A new RateLimiter instance
+    pub fn new(limit: RateLimit) -> Self {
+        RateLimiter {
+            base_url: PAYSTACK_BASE_URL.to_string(),
+            global: TokenBucket::new(limit),
+            routes: vec![],
+        }
+    }
+
+    /// Sets the base URL the routes are relative to, defaults to `PAYSTACK_BASE_URL`.
+    /// Use the base URL the `PaystackClient` is built with, e.g. a gateway with a path prefix
+    /// like `https://gateway.example.com/paystack`.
+    ///
+    /// # Arguments
+    /// * `base_url` - The base URL of the Paystack API
+    ///
+    /// # Returns
+    /// The RateLimiter with the base URL
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Adds a limit for the requests to a route.
+    ///
+    /// # Arguments
+    /// * `route` - The path of the route relative to the base URL e.g. `/transaction/export`,
+    ///   optionally preceded by an HTTP method e.g. `POST /transfer`.
+    ///   A route ending in `/*` matches every path under it e.g. `/customer/*`.
+    ///   When several routes match a request, the longest one is used,
+    ///   and a route with a method is preferred to the same route without one.
+    /// * `limit` - The limit applied to the requests to the route
+    ///
+    /// # Returns
+    /// The RateLimiter with the route limit
+    pub fn with_route(mut self, route: &str, limit: RateLimit) -> Self {
+        let (method, path) = match route.trim().split_once(' ') {
+            Some((method, path)) => (Some(method.to_uppercase()), path.trim()),
+            None => (None, route.trim()),
+        };
+        self.routes.push(RouteLimit {
+            method,
+            path: path.to_string(),
+            bucket: TokenBucket::new(limit),
+        });
+        self
+    }
+
+    /// Waits until a request to the given URL is allowed by the limits.
+    ///
+    /// # Arguments
+    /// * `method` - The HTTP method of the request e.g. `GET`
+    /// * `url` - The URL of the request
+    pub async fn acquire(&self, method: &str, url: &str) {
+        if let Some(bucket) = self.route_bucket(method, url) {
+            bucket.acquire().await;
+        }
+        self.global.acquire().await;
+    }
+
+    /// Finds the bucket of the longest route matching the request
+    fn route_bucket(&self, method: &str, url: &str) -> Option<&TokenBucket> {
+        let path = self.route_path(url)?;
+        let path = path.trim_end_matches('/');
+
+        self.routes
+            .iter()
+            .filter(|route| route.matches(method, path))
+            .max_by_key(|route| (route.path.len(), route.method.is_some()))
+            .map(|route| &route.bucket)
+    }
+
+    /// The path of a URL relative to the base URL.
+    /// URLs outside the base URL fall back to their full path.
+    fn route_path(&self, url: &str) -> Option<String> {
+        let url = Url::parse(url).ok()?;
+        let base_path = Url::parse(&self.base_url)
+            .ok()
+            .filter(|base| base.origin() == url.origin())
+            .map(|base| base.path().trim_end_matches('/').to_string())
+            .unwrap_or_default();
+
+        match url.path().strip_prefix(&base_path) {
+            Some(path) if path.is_empty() || path.starts_with('/') => Some(path.to_string()),
+            _ => Some(url.path().to_string()),
+        }
+    }
+}
+
+/// An HTTP client that holds the requests of another client until they are allowed by a `RateLimiter`.
+///
+/// Without a rate limiter, requests are sent right away.
+/// Wrap this client in a `RetryClient` for retries to also be rate limited.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitedClient<T: HttpClient + Default> {
+    /// The client that sends the requests
+    inner: T,
+    /// Limiter shared by all clones of the client
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+impl<T: HttpClient + Default> RateLimitedClient<T> {
+    /// Creates a new RateLimitedClient instance
+    ///
+    /// # Arguments
+    /// * `inner` - The HTTP client that sends the requests
+    /// * `limiter` - The rate limiter applied to the requests
+    ///
+    /// # Returns
+    /// A new RateLimitedClient instance
+    pub fn new(inner: T, limiter: RateLimiter) -> RateLimitedClient<T> {
+        RateLimitedClient {
+            inner,
+            limiter: Some(Arc::new(limiter)),
+        }
+    }
+
+    async fn acquire(&self, method: &str, url: &str) {
+        if let Some(limiter) = &self.limiter {
+            limiter.acquire(method, url).await;
+        }
+    }
+}
+
+#[async_trait]
+impl<T> HttpClient for RateLimitedClient<T>
+where
+    T: HttpClient + Default + Sync,
+{
+    type Error = T::Error;
+
+    async fn get(
+        &self,
+        url: &str,
+        api_key: &str,
+        query: Option<&Query>,
+    ) -> Result<String, Self::Error> {
+        self.acquire("GET", url).await;
+        self.inner.get(url, api_key, query).await
+    }
+
+    async fn post(&self, url: &str, api_key: &str, body: &Value) -> Result<String, Self::Error> {
+        self.acquire("POST", url).await;
+        self.inner.post(url, api_key, body).await
+    }
+
+    async fn put(&self, url: &str, api_key: &str, body: &Value) -> Result<String, Self::Error> {
+        self.acquire("PUT", url).await;
+        self.inner.put(url, api_key, body).await
+    }
+
+    async fn delete(&self, url: &str, api_key: &str, body: &Value) -> Result<String, Self::Error> {
+        self.acquire("DELETE", url).await;
+        self.inner.delete(url, api_key, body).await
+    }
+}
+
+impl<T: HttpClient + Default + Sync> PaystackClient<RateLimitedClient<T>> {
+    /// Creates a new PaystackClient instance whose requests are limited by a rate limiter
+    /// shared across all the API routes.
+    ///
+    /// # Arguments
+    /// * `api_key` - The Paystack API key
+    /// * `limiter` - The rate limiter applied to the requests
+    ///
+    /// # Returns
+    /// A new PaystackClient instance
+    pub fn with_rate_limiter(api_key: String, limiter: RateLimiter) -> Self {
+        PaystackClient::with_http_client(api_key, RateLimitedClient::new(T::default(), limiter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE_URL: &str = "https://api.paystack.co";
+
+    #[tokio::test(start_paused = true)]
+    async fn requests_wait_once_the_burst_is_used_up() {
+        let limiter = RateLimiter::new(RateLimit::new(2, 20.0).unwrap());
+
+        let start = Instant::now();
+        limiter.acquire("GET", BASE_URL).await;
+        limiter.acquire("GET", BASE_URL).await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire("GET", BASE_URL).await;
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn route_limits_only_apply_to_their_routes() {
+        let limiter = RateLimiter::new(RateLimit::per_second(100).unwrap())
+            .with_route("/transaction/export", RateLimit::new(1, 10.0).unwrap());
+        let export = format!("{BASE_URL}/transaction/export");
+
+        let start = Instant::now();
+        limiter.acquire("GET", &export).await;
+        limiter
+            .acquire("GET", &format!("{BASE_URL}/transaction/1"))
+            .await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire("GET", &export).await;
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn rate_limit_rejects_invalid_rates() {
+        for rate in [f64::NAN, f64::INFINITY, 0.0, -1.0, f64::MIN_POSITIVE] {
+            assert!(RateLimit::new(1, rate).is_err());
+        }
+        assert!(RateLimit::per_second(0).is_err());
+        assert!(RateLimit::per_minute(0).is_err());
+
+        let limit = RateLimit::per_minute(30).unwrap();
+        assert_eq!(limit.burst(), 1);
+        assert_eq!(limit.rate(), 0.5);
+    }
+
+    fn burst(limiter: &RateLimiter, method: &str, url: &str) -> Option<u32> {
+        limiter
+            .route_bucket(method, url)
+            .map(|bucket| bucket.limit.burst())
+    }
+
+    #[test]
+    fn longest_matching_route_is_used() {
+        let limiter = RateLimiter::new(RateLimit::per_second(100).unwrap())
+            .with_route("/customer/*", RateLimit::per_second(1).unwrap())
+            .with_route(
+                "/customer/set_risk_action",
+                RateLimit::per_second(2).unwrap(),
+            )
+            .with_route("/customer", RateLimit::per_second(3).unwrap());
+        let burst = |path: &str| burst(&limiter, "GET", &format!("{BASE_URL}{path}"));
+
+        assert_eq!(burst("/customer"), Some(3));
+        assert_eq!(burst("/customer/CUS_123"), Some(1));
+        assert_eq!(burst("/customer/set_risk_action"), Some(2));
+        assert_eq!(burst("/transaction"), None);
+    }
+
+    #[test]
+    fn routes_can_be_limited_per_method() {
+        let limiter = RateLimiter::new(RateLimit::per_second(100).unwrap())
+            .with_route("/transfer", RateLimit::per_second(1).unwrap())
+            .with_route("post /transfer", RateLimit::per_second(2).unwrap());
+        let url = format!("{BASE_URL}/transfer");
+
+        assert_eq!(burst(&limiter, "POST", &url), Some(2));
+        assert_eq!(burst(&limiter, "GET", &url), Some(1));
+    }
+
+    #[test]
+    fn routes_are_relative_to_the_base_url() {
+        let base_url = "https://gateway.example.com/paystack/";
+        let limiter = RateLimiter::new(RateLimit::per_second(100).unwrap())
+            .with_base_url(base_url)
+            .with_route("/transaction", RateLimit::per_second(1).unwrap())
+            .with_route("/paystack/*", RateLimit::per_second(2).unwrap());
+
+        assert_eq!(
+            burst(
+                &limiter,
+                "GET",
+                "https://gateway.example.com/paystack/transaction?perPage=10"
+            ),
+            Some(1)
+        );
+        assert_eq!(
+            burst(&limiter, "GET", "https://gateway.example.com/transaction"),
+            Some(1)
+        );
+        assert_eq!(
+            burst(
+                &limiter,
+                "GET",
+                "https://other.example.com/paystack/transaction"
+            ),
+            Some(2)
+        );
+    }
+}
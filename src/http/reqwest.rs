@@ -1,10 +1,15 @@
 use super::ReqwestError;
 use crate::http::base::Query;
-use crate::HttpClient;
+use crate::{ApiError, HttpClient};
 use async_trait::async_trait;
-use reqwest::{Client, Method, RequestBuilder};
+use reqwest::{header::RETRY_AFTER, Client, Method, RequestBuilder};
 use serde_json::Value;
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
+
+/// Timeout for connecting to the API, used unless another one is configured
+pub(crate) const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Timeout for a whole request, used unless another one is configured
+pub(crate) const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
 
 #[derive(Debug, Clone)]
 pub struct ReqwestClient {
@@ -14,13 +19,29 @@ pub struct ReqwestClient {
 
 impl Default for ReqwestClient {
     fn default() -> Self {
-        let client = reqwest::ClientBuilder::new().build().unwrap();
+        let client = reqwest::ClientBuilder::new()
+            .connect_timeout(DEFAULT_CONNECT_TIMEOUT)
+            .timeout(DEFAULT_TIMEOUT)
+            .build()
+            .unwrap();
 
         Self { client }
     }
 }
 
 impl ReqwestClient {
+    /// Creates a new ReqwestClient instance that sends its requests with the given client.
+    /// This is used to configure the client, e.g. with timeouts or a proxy.
+    ///
+    /// # Arguments
+    /// * `client` - A configured reqwest client
+    ///
+    /// # Returns
+    /// A new ReqwestClient instance
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
     async fn send_request<D: Fn(RequestBuilder) -> RequestBuilder>(
         &self,
         method: Method,
@@ -46,7 +67,18 @@ impl ReqwestClient {
         if response.status().is_success() {
             response.text().await.map_err(Into::into)
         } else {
-            Err(ReqwestError::StatusCode(response))
+            let status = response.status().as_u16();
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok())
+                .map(Duration::from_secs);
+            let body = response.text().await?;
+            Err(ReqwestError::Api(Box::new(ApiError {
+                retry_after,
+                ..ApiError::from_response(status, &body)
+            })))
         }
     }
 }
@@ -108,8 +140,9 @@ mod tests {
                 ReqwestError::Reqwest(_) => {
                     // don't need this error here
                 }
-                ReqwestError::StatusCode(code) => {
-                    assert_eq!(code.status(), 401);
+                ReqwestError::Api(error) => {
+                    assert_eq!(error.status, 401);
+                    assert!(error.is_auth_error());
                 }
             }
         }
@@ -0,0 +1,357 @@
+//! Retries
+//! =======
+//! This file contains an HTTP client that retries failed requests with exponential backoff.
+//!
+//! Only requests that are safe to retry are retried: GET requests always, and POST
+//! requests when the body carries a `reference` or an `idempotency_key`, since Paystack
+//! rejects duplicate references. PUT and DELETE requests are never retried.
+
+use crate::http::base::Query;
+use crate::{HttpClient, HttpError};
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde_json::Value;
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// Configuration of how failed requests are retried.
+/// Use the `RetryPolicyBuilder` to create this object.
+#[derive(Debug, Clone, Builder)]
+#[builder(default, build_fn(validate = "Self::validate"))]
+pub struct RetryPolicy {
+    /// Maximum number of attempts for a request, including the first one, at least 1. Defaults to 3
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled on each following retry. Defaults to 200ms
+    pub base_delay: Duration,
+    /// Maximum delay between two attempts. Defaults to 5s
+    pub max_delay: Duration,
+    /// Fraction of the delay, between 0 and 1, that is randomly removed to spread retries. Defaults to 0.5
+    pub jitter: f64,
+    /// Wait for the duration in the `Retry-After` header of a response when it is set.
+    /// Requests the API asks to retry after more than `max_delay` are not retried. Defaults to true
+    pub respect_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            jitter: 0.5,
+            respect_retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if self.max_attempts == Some(0) {
+            return Err("max_attempts must be at least 1".to_string());
+        }
+        if let Some(jitter) = self.jitter {
+            if !(0.0..=1.0).contains(&jitter) {
+                return Err(format!("jitter must be between 0 and 1, got {jitter}"));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RetryPolicy {
+    /// Computes the delay before the next attempt.
+    ///
+    /// # Arguments
+    /// * `retry` - The number of the retry, starting at 1
+    /// * `retry_after` - The duration from the `Retry-After` header of the failed response
+    ///
+    /// # Returns
+    /// The duration to wait before the next attempt, never more than `max_delay`,
+    /// or `None` if the API asks to wait longer than `max_delay`
+    pub fn delay(&self, retry: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if let Some(retry_after) = retry_after.filter(|_| self.respect_retry_after) {
+            return (retry_after <= self.max_delay).then_some(retry_after);
+        }
+
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            .min(self.max_delay);
+        // A jitter set outside the builder may be invalid, it is then ignored
+        let jitter = match self.jitter {
+            jitter if jitter.is_finite() => jitter.clamp(0.0, 1.0),
+            _ => 0.0,
+        };
+
+        Some(backoff.mul_f64(1.0 - jitter * random_fraction()))
+    }
+}
+
+/// An HTTP client that retries the failed requests of another client according to a `RetryPolicy`.
+///
+/// Requests are retried on timeouts, connection errors, rate limiting and server errors,
+/// as reported by the `HttpError` implementation of the wrapped client's error.
+#[derive(Debug, Clone, Default)]
+pub struct RetryClient<T: HttpClient + Default> {
+    /// The client that sends the requests
+    inner: T,
+    /// How the failed requests are retried
+    policy: RetryPolicy,
+}
+
+impl<T: HttpClient + Default> RetryClient<T> {
+    /// Creates a new RetryClient instance
+    ///
+    /// # Arguments
+    /// * `inner` - The HTTP client that sends the requests
+    /// * `policy` - How the failed requests are retried
+    ///
+    /// # Returns
+    /// A new RetryClient instance
+    pub fn new(inner: T, policy: RetryPolicy) -> RetryClient<T> {
+        RetryClient { inner, policy }
+    }
+
+    /// Sends a request until it succeeds, fails with an error that is not retryable,
+    /// or runs out of attempts.
+    async fn send<'a, F, Fut>(&'a self, can_retry: bool, request: F) -> Result<String, T::Error>
+    where
+        F: Fn(&'a T) -> Fut,
+        Fut: std::future::Future<Output = Result<String, T::Error>>,
+    {
+        let mut attempt = 1;
+        loop {
+            let error = match request(&self.inner).await {
+                Ok(response) => return Ok(response),
+                Err(error) => error,
+            };
+
+            let retry_after = match retryable(&error) {
+                Some(retry_after) if can_retry && attempt < self.policy.max_attempts => retry_after,
+                _ => return Err(error),
+            };
+
+            let Some(delay) = self.policy.delay(attempt, retry_after) else {
+                return Err(error);
+            };
+            log::warn!("Request failed with {error}, retrying in {delay:?}");
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+#[async_trait]
+impl<T> HttpClient for RetryClient<T>
+where
+    T: HttpClient + Default + Sync,
+    T::Error: Send,
+{
+    type Error = T::Error;
+
+    async fn get(
+        &self,
+        url: &str,
+        api_key: &str,
+        query: Option<&Query>,
+    ) -> Result<String, Self::Error> {
+        self.send(true, |http| http.get(url, api_key, query)).await
+    }
+
+    async fn post(&self, url: &str, api_key: &str, body: &Value) -> Result<String, Self::Error> {
+        self.send(is_idempotent(body), |http| http.post(url, api_key, body))
+            .await
+    }
+
+    async fn put(&self, url: &str, api_key: &str, body: &Value) -> Result<String, Self::Error> {
+        self.inner.put(url, api_key, body).await
+    }
+
+    async fn delete(&self, url: &str, api_key: &str, body: &Value) -> Result<String, Self::Error> {
+        self.inner.delete(url, api_key, body).await
+    }
+}
+
+/// Checks if a POST body can be sent more than once without creating duplicates
+fn is_idempotent(body: &Value) -> bool {
+    ["reference", "idempotency_key"].iter().any(|key| {
+        body.get(key)
+            .is_some_and(|value| value.as_str().map_or(!value.is_null(), |v| !v.is_empty()))
+    })
+}
+
+/// Checks if an error is worth retrying.
+///
+/// # Returns
+/// `None` if the error should not be retried, otherwise the delay requested by the API, if any
+fn retryable<E: HttpError>(error: &E) -> Option<Option<Duration>> {
+    match error.api_error() {
+        Some(api_error) => api_error.is_retryable().then_some(api_error.retry_after),
+        None => error.is_transient().then_some(None),
+    }
+}
+
+/// Returns a random number between 0 and 1
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::fake::{FakeClient, FakeError};
+    use crate::ApiError;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// A client failing with the given status until `failures` requests have been sent
+    fn client(status: u16, failures: u32) -> (RetryClient<FakeClient>, FakeClient) {
+        let calls = AtomicU32::new(0);
+        let http = FakeClient::new(move |_| match calls.fetch_add(1, Ordering::SeqCst) {
+            call if call < failures => Err(FakeError::api(status, "")),
+            _ => Ok(r#"{"status": true, "message": "ok"}"#.to_string()),
+        });
+        let policy = RetryPolicyBuilder::default()
+            .base_delay(Duration::from_millis(1))
+            .build()
+            .unwrap();
+        (RetryClient::new(http.clone(), policy), http)
+    }
+
+    #[tokio::test]
+    async fn get_requests_are_retried_on_server_errors() {
+        let (http, calls) = client(503, 2);
+
+        let res = http.get("url", "key", None).await;
+
+        assert!(res.is_ok());
+        assert_eq!(calls.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn requests_are_not_retried_on_client_errors() {
+        let (http, calls) = client(400, 1);
+
+        let res = http.get("url", "key", None).await;
+
+        assert!(res.is_err());
+        assert_eq!(calls.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn requests_are_retried_on_transient_transport_errors() {
+        for (transient, attempts) in [(true, 2), (false, 1)] {
+            let calls = AtomicU32::new(0);
+            let http = FakeClient::new(move |_| match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(FakeError::transport(transient)),
+                _ => Ok(r#"{"status": true, "message": "ok"}"#.to_string()),
+            });
+            let policy = RetryPolicyBuilder::default()
+                .base_delay(Duration::from_millis(1))
+                .build()
+                .unwrap();
+
+            let res = RetryClient::new(http.clone(), policy)
+                .get("url", "key", None)
+                .await;
+
+            assert_eq!(res.is_ok(), transient);
+            assert_eq!(http.requests().len(), attempts);
+        }
+    }
+
+    #[tokio::test]
+    async fn requests_stop_after_max_attempts() {
+        let (http, calls) = client(429, 5);
+
+        let res = http.get("url", "key", None).await;
+
+        assert!(res.is_err());
+        assert_eq!(calls.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn post_requests_are_only_retried_with_a_reference() {
+        let (http, calls) = client(500, 1);
+        let res = http.post("url", "key", &json!({"email": "a@b.com"})).await;
+        assert!(res.is_err());
+        assert_eq!(calls.requests().len(), 1);
+
+        let (http, calls) = client(500, 1);
+        let res = http.post("url", "key", &json!({"reference": "ref"})).await;
+        assert!(res.is_ok());
+        assert_eq!(calls.requests().len(), 2);
+    }
+
+    #[test]
+    fn delay_grows_exponentially_up_to_max_delay() {
+        let policy = RetryPolicyBuilder::default()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(300))
+            .jitter(0.0)
+            .build()
+            .unwrap();
+
+        assert_eq!(policy.delay(1, None), Some(Duration::from_millis(100)));
+        assert_eq!(policy.delay(2, None), Some(Duration::from_millis(200)));
+        assert_eq!(policy.delay(3, None), Some(Duration::from_millis(300)));
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_millis(250))),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(policy.delay(1, Some(Duration::from_secs(3600))), None);
+    }
+
+    #[tokio::test]
+    async fn requests_are_not_retried_early_when_retry_after_exceeds_max_delay() {
+        let http = FakeClient::new(|_| {
+            let mut api_error = ApiError::from_response(429, "");
+            api_error.retry_after = Some(Duration::from_secs(3600));
+            Err(FakeError::Api(Box::new(api_error)))
+        });
+        let client = RetryClient::new(http.clone(), RetryPolicy::default());
+
+        let res = client.get("url", "key", None).await;
+
+        assert!(res.is_err());
+        assert_eq!(http.requests().len(), 1);
+    }
+
+    #[test]
+    fn builder_rejects_invalid_policies() {
+        for jitter in [f64::NAN, f64::INFINITY, -0.1, 1.5] {
+            assert!(RetryPolicyBuilder::default()
+                .jitter(jitter)
+                .build()
+                .is_err());
+        }
+        assert!(RetryPolicyBuilder::default()
+            .max_attempts(0)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn invalid_jitter_set_directly_is_ignored() {
+        let policy = RetryPolicy {
+            jitter: f64::NAN,
+            ..Default::default()
+        };
+
+        assert_eq!(policy.delay(1, None), Some(policy.base_delay));
+    }
+
+    #[test]
+    fn jitter_reduces_the_delay() {
+        let policy = RetryPolicy::default();
+
+        let delay = policy.delay(1, None).unwrap();
+
+        assert!(delay <= policy.base_delay);
+        assert!(delay >= policy.base_delay / 2);
+    }
+}
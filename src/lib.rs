@@ -71,7 +71,10 @@ pub mod errors;
 pub mod http;
 pub mod macros;
 pub mod models;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod utils;
+pub mod webhooks;
 
 // public re-export of modules
 pub use client::*;
@@ -80,6 +83,7 @@ pub use errors::*;
 pub use http::*;
 pub use models::*;
 pub use utils::*;
+pub use webhooks::*;
 
 /// Custom result type for the Paystack API
 pub type PaystackResult<T> = Result<Response<T>, PaystackAPIError>;
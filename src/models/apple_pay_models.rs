@@ -1,7 +1,98 @@
+//! Apple Pay
+//! =========
+//! This file contains the models and options for the Apple Pay endpoint of the Paystack API.
+
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
+/// This struct represents the domains registered for Apple Pay on your integration.
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct ApplePayResponseData {
+    /// Domains registered for Apple Pay
     #[serde(rename = "domainNames")]
-    pub domain_names: Vec<String>,
+    pub domain_names: Vec<ApplePayDomain>,
+}
+
+/// A domain registered for Apple Pay.
+///
+/// Paystack returns registered domains as plain names, this struct accepts
+/// either a plain name or a `{"domainName": ...}` record.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(from = "ApplePayDomainRepr")]
+pub struct ApplePayDomain {
+    /// Name of the domain
+    #[serde(rename = "domainName")]
+    pub domain_name: String,
+}
+
+impl ApplePayDomain {
+    /// Checks if this domain has the given name. Domain names are case insensitive.
+    pub fn matches(&self, domain_name: &str) -> bool {
+        self.domain_name.eq_ignore_ascii_case(domain_name)
+    }
+}
+
+/// The shapes an Apple Pay domain can be returned in.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ApplePayDomainRepr {
+    Name(String),
+    Record {
+        #[serde(rename = "domainName")]
+        domain_name: String,
+    },
+}
+
+impl From<ApplePayDomainRepr> for ApplePayDomain {
+    fn from(repr: ApplePayDomainRepr) -> Self {
+        match repr {
+            ApplePayDomainRepr::Name(domain_name) | ApplePayDomainRepr::Record { domain_name } => {
+                ApplePayDomain { domain_name }
+            }
+        }
+    }
+}
+
+/// Optional parameters for paginating the domains returned by the list domains endpoint.
+/// Use the `ListApplePayDomainsFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListApplePayDomainsFilter {
+    /// Set to `true` to use cursor based pagination
+    pub use_cursor: Option<bool>,
+    /// A cursor that indicates your place in the list, used to fetch the next page
+    pub next: Option<String>,
+    /// A cursor that indicates your place in the list, used to fetch the previous page
+    pub previous: Option<String>,
+}
+
+/// The outcome of registering or unregistering an Apple Pay domain.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ApplePayDomainChange {
+    /// Name of the domain
+    pub domain_name: String,
+    /// Whether the domain was registered before the request was made.
+    /// Registering a present domain and unregistering an absent one are no-ops.
+    pub already_present: bool,
+    /// Message returned by the Paystack API
+    pub message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn can_parse_domains_as_names_or_records() -> Result<(), Box<dyn Error>> {
+        let data: ApplePayResponseData = serde_json::from_value(serde_json::json!({
+            "domainNames": ["example.com", {"domainName": "pay.example.com"}]
+        }))?;
+
+        assert_eq!(data.domain_names.len(), 2);
+        assert_eq!(data.domain_names[0].domain_name, "example.com");
+        assert!(data.domain_names[1].matches("PAY.example.com"));
+
+        Ok(())
+    }
 }
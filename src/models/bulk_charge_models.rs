@@ -0,0 +1,217 @@
+//! Bulk Charges
+//! ============
+//! This file contains the models and options for the Bulk Charges endpoint of the Paystack API.
+
+use std::fmt;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{Authorization, CustomerResponseData, Domain, TransactionStatusData};
+
+/// A single charge in a bulk charge batch.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BulkChargeItem {
+    /// Authorization code of the customer to charge
+    pub authorization: String,
+    /// Amount to charge, in the subunit of the currency
+    pub amount: String,
+    /// Unique reference for the charge
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+}
+
+impl BulkChargeItem {
+    /// Creates a bulk charge item from a saved authorization.
+    ///
+    /// # Arguments
+    /// * `authorization` - The saved authorization to charge
+    /// * `amount` - Amount to charge, in the subunit of the currency
+    ///
+    /// # Returns
+    /// The bulk charge item, or `None` if the authorization has no `authorization_code`
+    pub fn from_authorization(authorization: &Authorization, amount: String) -> Option<Self> {
+        authorization
+            .authorization_code
+            .clone()
+            .map(|authorization| BulkChargeItem {
+                authorization,
+                amount,
+                reference: None,
+            })
+    }
+
+    /// Sets the reference of the bulk charge item.
+    pub fn with_reference(mut self, reference: String) -> Self {
+        self.reference = Some(reference);
+        self
+    }
+}
+
+/// Optional parameters to filter the batches returned by the list bulk charge batches endpoint.
+/// Use the `ListBulkChargeBatchesFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListBulkChargeBatchesFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// A timestamp from which to start listing batches e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing batches e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+}
+
+/// Optional parameters to filter the charges returned by the fetch charges in a batch endpoint.
+/// Use the `ListBulkChargesFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListBulkChargesFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// Filter charges by status
+    pub status: Option<BulkChargeStatus>,
+    /// A timestamp from which to start listing charges e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing charges e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+}
+
+/// Represents the status of a bulk charge batch.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum BulkChargeBatchStatus {
+    /// The batch is being processed
+    #[default]
+    Active,
+    /// The batch has been paused
+    Paused,
+    /// All charges in the batch have been processed
+    Complete,
+}
+
+impl fmt::Display for BulkChargeBatchStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            BulkChargeBatchStatus::Active => "active",
+            BulkChargeBatchStatus::Paused => "paused",
+            BulkChargeBatchStatus::Complete => "complete",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// Represents the status of a charge in a bulk charge batch.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum BulkChargeStatus {
+    /// The charge has not been attempted
+    #[default]
+    Pending,
+    /// The charge was successful
+    Success,
+    /// The charge failed
+    Failed,
+}
+
+impl fmt::Display for BulkChargeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            BulkChargeStatus::Pending => "pending",
+            BulkChargeStatus::Success => "success",
+            BulkChargeStatus::Failed => "failed",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// This struct represents a bulk charge batch.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BulkChargeBatchData {
+    /// ID of the batch
+    pub id: u64,
+    /// Code of the batch
+    pub batch_code: String,
+    /// Reference of the batch
+    pub reference: Option<String>,
+    /// Status of the batch
+    pub status: BulkChargeBatchStatus,
+    /// Domain of the batch
+    pub domain: Option<Domain>,
+    /// Integration the batch belongs to
+    pub integration: Option<u64>,
+    /// Total number of charges in the batch
+    pub total_charges: Option<u64>,
+    /// Number of charges in the batch yet to be processed
+    pub pending_charges: Option<u64>,
+    /// Creation time of the batch
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    /// Last update time of the batch
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+}
+
+/// This struct represents a single charge in a bulk charge batch.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BulkChargeData {
+    /// ID of the charge
+    pub id: u64,
+    /// Integration the charge belongs to
+    pub integration: Option<u64>,
+    /// Bulk charge batch ID
+    pub bulkcharge: Option<u64>,
+    /// Customer that was charged
+    pub customer: Option<CustomerResponseData>,
+    /// Authorization that was charged
+    pub authorization: Option<Authorization>,
+    /// Transaction created by the charge, once it has been attempted
+    pub transaction: Option<TransactionStatusData>,
+    /// Domain of the charge
+    pub domain: Option<Domain>,
+    /// Amount of the charge, in the subunit of the currency
+    pub amount: u64,
+    /// Currency of the charge
+    pub currency: Option<String>,
+    /// Status of the charge
+    pub status: BulkChargeStatus,
+    /// Creation time of the charge
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    /// Last update time of the charge
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_create_bulk_charge_item_from_authorization() {
+        let authorization = Authorization {
+            authorization_code: Some("AUTH_n95vpedf".to_string()),
+            ..Default::default()
+        };
+
+        let item = BulkChargeItem::from_authorization(&authorization, "2500".to_string())
+            .expect("authorization has a code")
+            .with_reference("dam1266638dhhd".to_string());
+
+        let body = serde_json::to_value(item).expect("unable to serialize item");
+        assert_eq!(body["authorization"], "AUTH_n95vpedf");
+        assert_eq!(body["reference"], "dam1266638dhhd");
+    }
+
+    #[test]
+    fn cannot_create_bulk_charge_item_without_authorization_code() {
+        let item = BulkChargeItem::from_authorization(&Authorization::default(), "2500".into());
+
+        assert!(item.is_none());
+    }
+}
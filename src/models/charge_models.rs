@@ -6,6 +6,7 @@
 use crate::{Channel, Currency};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 use super::{Authorization, CustomerResponseData};
 
@@ -71,3 +72,248 @@ pub struct ChargeResponseData {
     pub plan: Option<String>,
     pub id: Option<u64>,
 }
+
+/// This struct is used to create the body for a direct charge using the Paystack API.
+/// The payment instrument is supplied with one of `bank`, `authorization_code`, `ussd`,
+/// `mobile_money`, or `bank_transfer`. Use the `DirectChargeRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct DirectChargeRequest {
+    /// Customer's email address
+    pub email: String,
+    /// Amount should be in the smallest unit of the currency e.g. kobo if in NGN and cents if in USD
+    pub amount: String,
+    /// Bank account to charge
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank: Option<ChargeBank>,
+    /// Takes the settings for the Pay with Transfer (PwT) channel
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_transfer: Option<ChargeBankTransfer>,
+    /// An authorization code to charge
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_code: Option<String>,
+    /// 4-digit PIN (send with a non-reusable authorization code)
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pin: Option<String>,
+    /// USSD type to charge
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ussd: Option<ChargeUssd>,
+    /// Mobile money details to charge
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mobile_money: Option<ChargeMobileMoney>,
+    /// Unique transaction reference. Only `-`, `.`, `=` and alphanumeric characters allowed.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// Currency in which amount should be charged.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    /// Stringified JSON object of custom data
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+    /// This is the unique identifier of the device a user uses in making payment.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_id: Option<String>,
+    /// Customer's birthday in the format `YYYY-MM-DD`, required by some banks
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birthday: Option<String>,
+}
+
+/// Bank account details for a direct bank charge.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ChargeBank {
+    /// Bank code
+    pub code: String,
+    /// 10-digit NUBAN account number
+    pub account_number: String,
+}
+
+/// Settings for a Pay with Transfer charge.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ChargeBankTransfer {
+    /// ISO 8601 timestamp after which the generated account number expires
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_expires_at: Option<String>,
+}
+
+/// USSD details for a direct USSD charge.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ChargeUssd {
+    /// USSD code of the bank e.g. `737` for GTBank
+    #[serde(rename = "type")]
+    pub ussd_type: String,
+}
+
+/// Mobile money details for a direct mobile money charge.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ChargeMobileMoney {
+    /// Customer's mobile number
+    pub phone: String,
+    /// Mobile money provider e.g. `mtn`, `atl`, `vod`, `tgo` or `mpesa`
+    pub provider: String,
+}
+
+/// This struct is used to create the body for submitting an address to continue a charge.
+/// Use the `SubmitAddressRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct SubmitAddressRequest {
+    /// Address submitted by user
+    pub address: String,
+    /// City submitted by user
+    pub city: String,
+    /// State submitted by user
+    pub state: String,
+    /// Zipcode submitted by user
+    pub zipcode: String,
+    /// Reference for ongoing transaction
+    pub reference: String,
+}
+
+/// Represents the state of a direct charge.
+///
+/// Every state other than `Success`, `Failed` and `Timeout` requires an action
+/// before the charge can be completed. The variant documentation names the
+/// `ChargeEndpoints` method to call next.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ChargeStatus {
+    /// The charge is being processed. Call `check_pending_charge` after at least 10 seconds.
+    #[default]
+    Pending,
+    /// The customer's PIN is required. Call `submit_pin`.
+    SendPin,
+    /// An OTP sent to the customer is required. Call `submit_otp`.
+    SendOtp,
+    /// The customer's phone number is required. Call `submit_phone`.
+    SendPhone,
+    /// The customer's birthday is required. Call `submit_birthday`.
+    SendBirthday,
+    /// The customer's address is required. Call `submit_address`.
+    SendAddress,
+    /// The customer must complete authentication at the returned `url`,
+    /// then call `check_pending_charge`.
+    OpenUrl,
+    /// The customer must complete the payment offline e.g. by dialing a USSD code
+    /// or approving on their phone. Listen for webhooks or call `check_pending_charge`.
+    PayOffline,
+    /// The charge was successful.
+    Success,
+    /// The charge failed.
+    Failed,
+    /// The charge timed out.
+    Timeout,
+}
+
+impl ChargeStatus {
+    /// Returns `true` if the charge has reached a final state and no further action is possible.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            ChargeStatus::Success | ChargeStatus::Failed | ChargeStatus::Timeout
+        )
+    }
+}
+
+impl fmt::Display for ChargeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            ChargeStatus::Pending => "pending",
+            ChargeStatus::SendPin => "send_pin",
+            ChargeStatus::SendOtp => "send_otp",
+            ChargeStatus::SendPhone => "send_phone",
+            ChargeStatus::SendBirthday => "send_birthday",
+            ChargeStatus::SendAddress => "send_address",
+            ChargeStatus::OpenUrl => "open_url",
+            ChargeStatus::PayOffline => "pay_offline",
+            ChargeStatus::Success => "success",
+            ChargeStatus::Failed => "failed",
+            ChargeStatus::Timeout => "timeout",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// This struct represents the response of a direct charge and its follow up submissions.
+/// Only `reference` and `status` are always present; the other fields depend on the state.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DirectChargeResponseData {
+    /// Reference of the charge
+    pub reference: String,
+    /// Current state of the charge
+    pub status: ChargeStatus,
+    /// Text to show the customer for the next step
+    pub display_text: Option<String>,
+    /// URL to open when the status is `open_url`
+    pub url: Option<String>,
+    /// USSD code to dial when paying with USSD
+    pub ussd_code: Option<String>,
+    /// Message from the charge
+    pub message: Option<String>,
+    /// Response from the payment gateway
+    pub gateway_response: Option<String>,
+    /// ID of the transaction, present once the charge has completed
+    pub id: Option<u64>,
+    /// Amount of the charge in the subunit of the currency
+    pub amount: Option<u64>,
+    /// Currency of the charge
+    pub currency: Option<String>,
+    /// Channel of the charge
+    pub channel: Option<String>,
+    /// Fees of the charge
+    pub fees: Option<u64>,
+    /// Time the charge was completed
+    pub paid_at: Option<String>,
+    /// Authorization data of the charge, present once the charge has completed
+    pub authorization: Option<Authorization>,
+    /// Customer of the charge, present once the charge has completed
+    pub customer: Option<CustomerResponseData>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn can_create_direct_charge_request_with_builder() -> Result<(), Box<dyn Error>> {
+        let request = DirectChargeRequestBuilder::default()
+            .email("customer@email.com".to_string())
+            .amount("10000".to_string())
+            .bank(ChargeBank {
+                code: "057".to_string(),
+                account_number: "0000000000".to_string(),
+            })
+            .build()?;
+
+        let body = serde_json::to_value(request)?;
+        assert_eq!(body["bank"]["code"], "057");
+        assert!(body.get("ussd").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_parse_direct_charge_response_states() {
+        let response: DirectChargeResponseData = serde_json::from_value(serde_json::json!({
+            "reference": "5bwib5v6anhe9xa",
+            "status": "send_otp",
+            "display_text": "Please send OTP"
+        }))
+        .expect("unable to parse charge response");
+
+        assert_eq!(response.status, ChargeStatus::SendOtp);
+        assert!(!response.status.is_final());
+        assert_eq!(ChargeStatus::PayOffline.to_string(), "pay_offline");
+        assert!(ChargeStatus::Success.is_final());
+    }
+}
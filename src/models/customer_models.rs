@@ -40,6 +40,57 @@ pub struct CustomerMetaData {
     pub calling_code: Option<String>,
 }
 
+/// A customer referenced from another object, such as a subscription, an order or a payment request.
+///
+/// Paystack returns the customer ID or the full customer object depending on the route.
+/// When sent in a request, the customer is identified by its code.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum CustomerReference {
+    /// ID of the customer
+    Id(u64),
+    /// Customer code e.g. `CUS_xwaj0txjryg393b`
+    Code(String),
+    /// Full customer object
+    Customer(Box<CustomerResponseData>),
+}
+
+impl CustomerReference {
+    /// Returns the value Paystack expects when identifying the customer in a request.
+    pub(crate) fn identifier(&self) -> String {
+        match self {
+            CustomerReference::Id(id) => id.to_string(),
+            CustomerReference::Code(code) => code.clone(),
+            CustomerReference::Customer(customer) => customer.customer_code.clone(),
+        }
+    }
+}
+
+/// Defaults to the ID `0`, which Paystack never assigns to a customer.
+impl Default for CustomerReference {
+    fn default() -> Self {
+        CustomerReference::Id(0)
+    }
+}
+
+impl From<String> for CustomerReference {
+    fn from(code: String) -> Self {
+        CustomerReference::Code(code)
+    }
+}
+
+impl From<&str> for CustomerReference {
+    fn from(code: &str) -> Self {
+        CustomerReference::Code(code.to_string())
+    }
+}
+
+impl From<CustomerResponseData> for CustomerReference {
+    fn from(customer: CustomerResponseData) -> Self {
+        CustomerReference::Customer(Box::new(customer))
+    }
+}
+
 /// This struct constains the data for creating a customer in your integration
 #[derive(Debug, Clone, Serialize, Default, Deserialize, Builder)]
 pub struct CreateCustomerRequest {
@@ -116,12 +167,16 @@ impl fmt::Display for IdentificationType {
     }
 }
 
+/// Represents the risk action applied to a customer.
 #[derive(Debug, Serialize, Default, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum RiskAction {
+    /// No risk action, the customer is treated normally
     #[default]
     Default,
+    /// Whitelist the customer
     Allow,
+    /// Blacklist the customer
     Deny,
 }
 
@@ -136,6 +191,127 @@ impl fmt::Display for RiskAction {
     }
 }
 
+/// Optional parameters to filter the customers returned by the list customers endpoint.
+/// Use the `ListCustomersFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListCustomersFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// A timestamp from which to start listing customers e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing customers e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+}
+
+/// Represents the channel of a customer authorization.
+#[derive(Debug, Serialize, Default, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorizationChannel {
+    /// Direct debit from the customer's bank account
+    #[default]
+    DirectDebit,
+}
+
+impl fmt::Display for AuthorizationChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let channel = match self {
+            AuthorizationChannel::DirectDebit => "direct_debit",
+        };
+        write!(f, "{channel}")
+    }
+}
+
+/// The bank account to set up a direct debit mandate on.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DirectDebitAccount {
+    /// The customer's account number
+    pub number: String,
+    /// The code for the customer's bank
+    pub bank_code: String,
+}
+
+/// The address of the customer setting up a direct debit mandate.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DirectDebitAddress {
+    /// The customer's street
+    pub street: String,
+    /// The customer's city
+    pub city: String,
+    /// The customer's state
+    pub state: String,
+}
+
+/// This struct is used to create the body for initializing a customer authorization.
+/// Use the `InitializeAuthorizationRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct InitializeAuthorizationRequest {
+    /// Customer's email address
+    pub email: String,
+    /// Channel of the authorization. Only `direct_debit` is supported at the moment
+    #[builder(default)]
+    pub channel: AuthorizationChannel,
+    /// Fully qualified url (e.g. `https://example.com/`) to redirect your customer to
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<String>,
+    /// The customer's bank account. When supplied, the customer skips the bank selection step
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<DirectDebitAccount>,
+    /// The customer's address
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<DirectDebitAddress>,
+}
+
+/// This struct is used to create the body for initializing a direct debit on an existing customer.
+/// Use the `InitializeDirectDebitRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct InitializeDirectDebitRequest {
+    /// The customer's bank account
+    pub account: DirectDebitAccount,
+    /// The customer's address
+    pub address: DirectDebitAddress,
+}
+
+/// This struct represents the response of initializing a customer authorization or direct debit.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct InitializeAuthorizationData {
+    /// URL to redirect the customer to, to complete the authorization
+    pub redirect_url: String,
+    /// Access code of the authorization
+    pub access_code: String,
+    /// Reference of the authorization, used to verify it
+    pub reference: String,
+}
+
+/// The customer an authorization belongs to.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AuthorizationCustomer {
+    /// Customer code
+    pub code: String,
+    /// Customer's email address
+    pub email: String,
+}
+
+/// This struct represents the result of verifying a customer authorization.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VerifyAuthorizationData {
+    /// Authorization code to use for charges
+    pub authorization_code: String,
+    /// Channel of the authorization
+    pub channel: AuthorizationChannel,
+    /// Bank of the authorization
+    pub bank: Option<String>,
+    /// Whether the authorization is active
+    pub active: bool,
+    /// Customer the authorization belongs to
+    pub customer: AuthorizationCustomer,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,10 +345,42 @@ mod tests {
         assert!(body.is_err());
     }
 
+    #[test]
+    fn can_parse_customer_reference_as_id_code_or_object() {
+        let references: Vec<CustomerReference> = serde_json::from_value(serde_json::json!([
+            1173,
+            "CUS_xnxdt6s1zg1f4nx",
+            {"id": 1173, "email": "customer@email.com", "customer_code": "CUS_xnxdt6s1zg1f4nx"}
+        ]))
+        .expect("unable to parse customer references");
+
+        assert!(matches!(references[0], CustomerReference::Id(1173)));
+        assert!(matches!(references[1], CustomerReference::Code(_)));
+        assert!(matches!(references[2], CustomerReference::Customer(_)));
+        assert_eq!(references[2].identifier(), "CUS_xnxdt6s1zg1f4nx");
+    }
+
     #[test]
     fn can_use_identification_type() {
         let identification = IdentificationType::BankAccount;
 
         assert_eq!(identification.to_string(), "bank_account".to_string());
     }
+
+    #[test]
+    fn can_build_initialize_authorization_request_with_default_channel() {
+        let request = InitializeAuthorizationRequestBuilder::default()
+            .email("customer@example.com".to_string())
+            .account(DirectDebitAccount {
+                number: "0123456789".to_string(),
+                bank_code: "058".to_string(),
+            })
+            .build()
+            .expect("unable to build authorization request");
+
+        let body = serde_json::to_value(request).expect("unable to serialize request");
+        assert_eq!(body["channel"], "direct_debit");
+        assert_eq!(body["account"]["bank_code"], "058");
+        assert!(body.get("address").is_none());
+    }
 }
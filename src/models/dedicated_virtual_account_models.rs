@@ -78,6 +78,7 @@ pub struct Assignment {
     pub assignee_type: String,
     pub expired: bool,
     pub account_type: String,
+    #[serde(alias = "assigned_at")]
     pub assinged_at: String,
 }
 
@@ -0,0 +1,142 @@
+//! Direct Debit
+//! ============
+//! This file contains the models and options for the Direct Debit endpoint of the Paystack API.
+
+use std::fmt;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{Authorization, CustomerResponseData};
+
+/// Represents the status of a direct debit mandate.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum MandateStatus {
+    /// The mandate is awaiting approval from the customer or their bank
+    #[default]
+    Pending,
+    /// The mandate is approved and the customer can be charged
+    Active,
+    /// The mandate has been revoked and the customer can no longer be charged
+    Revoked,
+}
+
+impl fmt::Display for MandateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            MandateStatus::Pending => "pending",
+            MandateStatus::Active => "active",
+            MandateStatus::Revoked => "revoked",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// Optional parameters to filter the mandates returned by the list mandate authorizations endpoint.
+/// Use the `ListMandateAuthorizationsFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListMandateAuthorizationsFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// The cursor value of the next set of mandates, found in the `meta.next` of the previous response
+    pub cursor: Option<String>,
+    /// Filter by mandate status
+    pub status: Option<MandateStatus>,
+}
+
+/// This struct represents a direct debit mandate authorization of a customer.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MandateAuthorizationData {
+    /// ID of the mandate authorization
+    pub id: u64,
+    /// Status of the mandate
+    pub status: MandateStatus,
+    /// ID of the mandate
+    pub mandate_id: Option<u64>,
+    /// ID of the authorization linked to the mandate
+    pub authorization_id: Option<u64>,
+    /// Code of the authorization linked to the mandate, used to charge the customer
+    pub authorization_code: Option<String>,
+    /// The authorization linked to the mandate, when Paystack returns the full object.
+    /// Otherwise only `authorization_id` and `authorization_code` are set.
+    pub authorization: Option<Authorization>,
+    /// Integration the mandate belongs to
+    pub integration_id: Option<u64>,
+    /// Account number of the mandate
+    pub account_number: Option<String>,
+    /// Bank code of the mandate
+    pub bank_code: Option<String>,
+    /// Bank name of the mandate
+    pub bank_name: Option<String>,
+    /// Customer the mandate belongs to
+    pub customer: Option<CustomerResponseData>,
+    /// Time the mandate was authorized
+    pub authorized_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_mandate_authorization() {
+        let mandate: MandateAuthorizationData = serde_json::from_value(serde_json::json!({
+            "id": 510,
+            "status": "active",
+            "mandate_id": 1380,
+            "authorization_id": 3872,
+            "authorization_code": "AUTH_4kar8v8ifz",
+            "integration_id": 1033,
+            "account_number": "0123456789",
+            "bank_code": "058",
+            "bank_name": "Guaranty Trust Bank",
+            "customer": {
+                "id": 1800,
+                "customer_code": "CUS_24lze1c8i2zl76y",
+                "email": "customer@example.com",
+                "first_name": "Ada",
+                "last_name": "Obi"
+            },
+            "authorized_at": "2024-10-09T10:15:16.000Z"
+        }))
+        .expect("unable to parse mandate");
+
+        assert_eq!(mandate.status, MandateStatus::Active);
+        assert_eq!(
+            mandate.customer.as_ref().unwrap().customer_code,
+            "CUS_24lze1c8i2zl76y"
+        );
+        assert_eq!(
+            mandate.authorization_code.as_deref(),
+            Some("AUTH_4kar8v8ifz")
+        );
+        assert!(mandate.authorization.is_none());
+    }
+
+    #[test]
+    fn can_parse_mandate_authorization_with_authorization() {
+        let mandate: MandateAuthorizationData = serde_json::from_value(serde_json::json!({
+            "id": 510,
+            "status": "active",
+            "authorization_code": "AUTH_4kar8v8ifz",
+            "authorization": {
+                "authorization_code": "AUTH_4kar8v8ifz",
+                "channel": "direct_debit",
+                "bank": "Guaranty Trust Bank",
+                "last4": "6789",
+                "reusable": true
+            }
+        }))
+        .expect("unable to parse mandate");
+
+        let authorization = mandate.authorization.unwrap();
+        assert_eq!(
+            authorization.authorization_code.as_deref(),
+            Some("AUTH_4kar8v8ifz")
+        );
+        assert_eq!(authorization.channel.as_deref(), Some("direct_debit"));
+    }
+}
@@ -0,0 +1,279 @@
+//! Disputes
+//! ========
+//! This file contains the models and options for the Disputes endpoint of the Paystack API.
+
+use std::fmt;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Currency, CustomerResponseData, Domain, TransactionStatusData};
+
+/// Represents the status of a dispute.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum DisputeStatus {
+    /// The dispute is waiting for the merchant to respond
+    #[default]
+    AwaitingMerchantFeedback,
+    /// The dispute is waiting for the bank to respond
+    AwaitingBankFeedback,
+    /// The dispute is pending
+    Pending,
+    /// The dispute has been resolved
+    Resolved,
+    /// The dispute has been archived
+    Archived,
+}
+
+impl fmt::Display for DisputeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            DisputeStatus::AwaitingMerchantFeedback => "awaiting-merchant-feedback",
+            DisputeStatus::AwaitingBankFeedback => "awaiting-bank-feedback",
+            DisputeStatus::Pending => "pending",
+            DisputeStatus::Resolved => "resolved",
+            DisputeStatus::Archived => "archived",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// Represents the resolution of a dispute.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DisputeResolution {
+    /// The merchant accepted the dispute and refunds the customer
+    #[default]
+    MerchantAccepted,
+    /// The merchant declined the dispute
+    Declined,
+}
+
+impl fmt::Display for DisputeResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let resolution = match self {
+            DisputeResolution::MerchantAccepted => "merchant-accepted",
+            DisputeResolution::Declined => "declined",
+        };
+        write!(f, "{resolution}")
+    }
+}
+
+/// Optional parameters to filter the disputes returned by the list and export disputes endpoints.
+/// Use the `ListDisputesFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListDisputesFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// A timestamp from which to start listing disputes e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing disputes e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+    /// Transaction ID to list disputes for
+    pub transaction: Option<String>,
+    /// Dispute status
+    pub status: Option<DisputeStatus>,
+}
+
+/// This struct is used to create the body for updating a dispute on your integration.
+/// Use the `UpdateDisputeRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct UpdateDisputeRequest {
+    /// The amount to refund, in the subunit of the currency
+    pub refund_amount: String,
+    /// Filename of attachment returned via response from upload url (`get_upload_url`)
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uploaded_filename: Option<String>,
+}
+
+/// This struct is used to create the body for adding evidence to a dispute.
+/// Use the `DisputeEvidenceRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct DisputeEvidenceRequest {
+    /// Customer email
+    pub customer_email: String,
+    /// Customer name
+    pub customer_name: String,
+    /// Customer phone
+    pub customer_phone: String,
+    /// Details of service involved
+    pub service_details: String,
+    /// Delivery Address
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_address: Option<String>,
+    /// ISO 8601 representation of delivery date (YYYY-MM-DD)
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_date: Option<String>,
+}
+
+/// This struct is used to create the body for resolving a dispute on your integration.
+/// Use the `ResolveDisputeRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct ResolveDisputeRequest {
+    /// Dispute resolution
+    pub resolution: DisputeResolution,
+    /// Reason for resolving
+    pub message: String,
+    /// The amount to refund, in the subunit of the currency
+    pub refund_amount: String,
+    /// Filename of attachment returned via response from upload url (`get_upload_url`)
+    pub uploaded_filename: String,
+    /// Evidence ID for fraud claims
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<u64>,
+}
+
+/// This struct represents the data of a dispute.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DisputeResponseData {
+    /// ID of the dispute
+    pub id: u64,
+    /// Amount to be refunded, in the subunit of the currency
+    pub refund_amount: Option<u64>,
+    /// Currency of the dispute
+    pub currency: Option<Currency>,
+    /// Status of the dispute
+    pub status: DisputeStatus,
+    /// Resolution of the dispute, if it has been resolved
+    pub resolution: Option<DisputeResolution>,
+    /// Domain of the dispute
+    pub domain: Option<Domain>,
+    /// The disputed transaction
+    pub transaction: Option<TransactionStatusData>,
+    /// Reference of the disputed transaction
+    pub transaction_reference: Option<String>,
+    /// Category of the dispute e.g. `chargeback` or `fraud`
+    pub category: Option<String>,
+    /// The customer who raised the dispute
+    pub customer: Option<CustomerResponseData>,
+    /// Card BIN of the disputed transaction
+    pub bin: Option<String>,
+    /// Last 4 digits of the card of the disputed transaction
+    pub last4: Option<String>,
+    /// Time by which the merchant must respond to the dispute
+    #[serde(rename = "dueAt")]
+    pub due_at: Option<String>,
+    /// Time the dispute was resolved
+    #[serde(rename = "resolvedAt")]
+    pub resolved_at: Option<String>,
+    /// Evidence attached to the dispute
+    pub evidence: Option<Value>,
+    /// Attachments of the dispute
+    pub attachments: Option<Value>,
+    /// Note attached to the dispute
+    pub note: Option<String>,
+    /// History of the dispute
+    pub history: Option<Vec<Value>>,
+    /// Messages exchanged on the dispute
+    pub messages: Option<Vec<Value>>,
+    /// Creation time of the dispute
+    #[serde(rename = "createdAt", alias = "created_at")]
+    pub created_at: Option<String>,
+    /// Last update time of the dispute
+    #[serde(rename = "updatedAt", alias = "updated_at")]
+    pub updated_at: Option<String>,
+}
+
+/// This struct represents the evidence added to a dispute.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DisputeEvidenceData {
+    /// ID of the evidence
+    pub id: u64,
+    /// Customer email
+    pub customer_email: String,
+    /// Customer name
+    pub customer_name: String,
+    /// Customer phone
+    pub customer_phone: String,
+    /// Details of service involved
+    pub service_details: String,
+    /// Delivery Address
+    pub delivery_address: Option<String>,
+    /// Delivery date
+    pub delivery_date: Option<String>,
+    /// ID of the dispute the evidence belongs to
+    pub dispute: u64,
+    /// Creation time of the evidence
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    /// Last update time of the evidence
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+}
+
+/// This struct represents the URL to upload a dispute evidence to.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DisputeUploadUrlData {
+    /// Signed URL to upload the file to
+    #[serde(rename = "signedUrl")]
+    pub signed_url: String,
+    /// Name of the file to use when updating or resolving the dispute
+    #[serde(rename = "fileName")]
+    pub file_name: String,
+    /// Time the signed URL expires
+    #[serde(rename = "expiresIn")]
+    pub expires_in: Option<u64>,
+}
+
+/// Export disputes response data.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ExportDisputeData {
+    /// Path to download the exported disputes file.
+    pub path: String,
+    /// Time the download path expires
+    #[serde(rename = "expiresAt")]
+    pub expires_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn can_create_resolve_dispute_request_with_builder() -> Result<(), Box<dyn Error>> {
+        let request = ResolveDisputeRequestBuilder::default()
+            .resolution(DisputeResolution::MerchantAccepted)
+            .message("Merchant accepted".to_string())
+            .refund_amount("1002".to_string())
+            .uploaded_filename("qesp8a4df1xejihd9x5q".to_string())
+            .build()?;
+
+        let body = serde_json::to_value(request)?;
+        assert_eq!(body["resolution"], "merchant-accepted");
+        assert!(body.get("evidence").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn cannot_create_dispute_evidence_request_without_compulsory_field() {
+        let request = DisputeEvidenceRequestBuilder::default()
+            .customer_email("cus@gmail.com".to_string())
+            .build();
+
+        assert!(request.is_err());
+    }
+
+    #[test]
+    fn can_use_dispute_status() {
+        let status: DisputeStatus = serde_json::from_str("\"awaiting-merchant-feedback\"")
+            .expect("unable to parse dispute status");
+
+        assert_eq!(status, DisputeStatus::AwaitingMerchantFeedback);
+        assert_eq!(
+            DisputeStatus::AwaitingBankFeedback.to_string(),
+            "awaiting-bank-feedback"
+        );
+    }
+}
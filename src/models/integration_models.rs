@@ -0,0 +1,26 @@
+//! Integration
+//! ===========
+//! This file contains the models for the Integration endpoint of the Paystack API.
+
+use serde::{Deserialize, Serialize};
+
+/// This struct represents the payment session timeout of the integration.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PaymentSessionTimeoutData {
+    /// Time in seconds before a payment session times out. `0` means sessions never time out.
+    pub payment_session_timeout: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_payment_session_timeout() {
+        let data: PaymentSessionTimeoutData =
+            serde_json::from_str(r#"{"payment_session_timeout": 30}"#)
+                .expect("unable to parse payment session timeout");
+
+        assert_eq!(data.payment_session_timeout, 30);
+    }
+}
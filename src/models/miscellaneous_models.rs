@@ -0,0 +1,123 @@
+//! Miscellaneous
+//! =============
+//! This file contains the models and options for the Miscellaneous endpoints of the Paystack API.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Currency;
+
+/// Optional parameters to filter the banks returned by the list banks endpoint.
+/// Use the `ListBanksFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListBanksFilter {
+    /// The country from which to obtain the list of supported banks e.g. `nigeria`, `ghana`, `kenya` or `south africa`
+    pub country: Option<String>,
+    /// Any of `NGN`, `USD`, `GHS`, `KES` or `ZAR`
+    pub currency: Option<Currency>,
+    /// Type of financial channel e.g. `nuban`, `ghipss`, `mobile_money` or `kepss`
+    pub bank_type: Option<String>,
+    /// Filter for banks that support Pay with Bank Transfer (dedicated virtual accounts)
+    pub pay_with_bank_transfer: Option<bool>,
+    /// Filter for banks a customer can pay directly from
+    pub pay_with_bank: Option<bool>,
+    /// The number of banks to return. Returns all banks when not set
+    pub per_page: Option<u32>,
+}
+
+/// This struct represents a bank supported by Paystack.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BankData {
+    /// ID of the bank
+    pub id: u64,
+    /// Name of the bank
+    pub name: String,
+    /// Slug of the bank
+    pub slug: String,
+    /// Bank code, used as `settlement_bank` or `bank_code` in other requests
+    pub code: String,
+    /// Long code of the bank
+    pub longcode: Option<String>,
+    /// Gateway of the bank
+    pub gateway: Option<String>,
+    /// Whether customers can pay directly from the bank
+    pub pay_with_bank: Option<bool>,
+    /// Whether the bank supports Pay with Bank Transfer
+    pub supports_transfer: Option<bool>,
+    /// Whether the bank is active
+    pub active: Option<bool>,
+    /// Whether the bank has been deleted
+    pub is_deleted: Option<bool>,
+    /// Country of the bank
+    pub country: Option<String>,
+    /// Currency of the bank
+    pub currency: Option<String>,
+    /// Type of financial channel of the bank
+    #[serde(rename = "type")]
+    pub bank_type: Option<String>,
+    /// Creation time of the bank
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    /// Last update time of the bank
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+}
+
+/// This struct represents a country supported by Paystack.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CountryData {
+    /// ID of the country
+    pub id: u64,
+    /// Name of the country
+    pub name: String,
+    /// Two digit ISO code of the country
+    pub iso_code: String,
+    /// Default currency code of the country
+    pub default_currency_code: Option<String>,
+    /// Default settings of integrations in the country
+    pub integration_defaults: Option<Value>,
+    /// Supported currencies and payment channels in the country
+    pub relationships: Option<Value>,
+}
+
+/// This struct represents a state used for address verification.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct StateData {
+    /// Name of the state
+    pub name: String,
+    /// Slug of the state
+    pub slug: String,
+    /// Abbreviation of the state
+    pub abbreviation: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_bank_data() {
+        let bank: BankData = serde_json::from_value(serde_json::json!({
+            "name": "Abbey Mortgage Bank",
+            "slug": "abbey-mortgage-bank",
+            "code": "801",
+            "longcode": "",
+            "gateway": null,
+            "pay_with_bank": false,
+            "active": true,
+            "is_deleted": false,
+            "country": "Nigeria",
+            "currency": "NGN",
+            "type": "nuban",
+            "id": 174,
+            "createdAt": "2020-12-07T16:19:09.000Z",
+            "updatedAt": "2020-12-07T16:19:19.000Z"
+        }))
+        .expect("unable to parse bank");
+
+        assert_eq!(bank.code, "801");
+        assert_eq!(bank.bank_type.as_deref(), Some("nuban"));
+    }
+}
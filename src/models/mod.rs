@@ -1,40 +1,74 @@
 pub mod apple_pay_models;
 pub mod authorization_models;
 pub mod bearer_models;
+pub mod bulk_charge_models;
 pub mod channel_models;
 pub mod charge_models;
 pub mod currency_models;
 pub mod customer_models;
 pub mod dedicated_virtual_account_models;
+pub mod direct_debit_models;
+pub mod dispute_models;
 pub mod domain_models;
+pub mod integration_models;
+pub mod miscellaneous_models;
+pub mod order_models;
+pub mod payment_page_models;
+pub mod payment_request_models;
 pub mod plans_models;
+pub mod product_models;
+pub mod refund_models;
 pub mod response_models;
+pub mod settlement_models;
 pub mod split_models;
 pub mod status_models;
+pub mod storefront_models;
 pub mod subaccount_models;
 pub mod subscription_models;
 pub mod terminal_models;
 pub mod transaction_models;
 pub mod transaction_split_models;
+pub mod transfer_control_models;
+pub mod transfer_models;
+pub mod transfer_recipient_models;
+pub mod verification_models;
 pub mod virtual_terminal_models;
+pub mod webhook_models;
 
 // public re-export
 pub use apple_pay_models::*;
 pub use authorization_models::*;
 pub use bearer_models::*;
+pub use bulk_charge_models::*;
 pub use channel_models::*;
 pub use charge_models::*;
 pub use currency_models::*;
 pub use customer_models::*;
 pub use dedicated_virtual_account_models::*;
+pub use direct_debit_models::*;
+pub use dispute_models::*;
 pub use domain_models::*;
+pub use integration_models::*;
+pub use miscellaneous_models::*;
+pub use order_models::*;
+pub use payment_page_models::*;
+pub use payment_request_models::*;
 pub use plans_models::*;
+pub use product_models::*;
+pub use refund_models::*;
 pub use response_models::*;
+pub use settlement_models::*;
 pub use split_models::*;
 pub use status_models::*;
+pub use storefront_models::*;
 pub use subaccount_models::*;
 pub use subscription_models::*;
 pub use terminal_models::*;
 pub use transaction_models::*;
 pub use transaction_split_models::*;
+pub use transfer_control_models::*;
+pub use transfer_models::*;
+pub use transfer_recipient_models::*;
+pub use verification_models::*;
 pub use virtual_terminal_models::*;
+pub use webhook_models::*;
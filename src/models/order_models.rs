@@ -0,0 +1,151 @@
+//! Orders
+//! ======
+//! This file contains the models and options for the Orders endpoint of the Paystack API.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Currency, CustomerReference, Domain};
+
+/// A product and its quantity in an order.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct OrderItem {
+    /// ID of the product
+    pub product: u64,
+    /// Quantity of the product
+    pub quantity: u32,
+    /// Amount of the product, in the subunit of the currency
+    pub amount: String,
+}
+
+/// This struct is used to create the body for creating an order.
+/// Use the `OrderRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct OrderRequest {
+    /// Customer's email address
+    pub email: String,
+    /// Customer's first name
+    pub first_name: String,
+    /// Customer's last name
+    pub last_name: String,
+    /// Customer's phone number
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /// Currency of the order
+    pub currency: Currency,
+    /// Products in the order
+    pub items: Vec<OrderItem>,
+    /// Shipping address of the order
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<Value>,
+    /// Whether the order is a gift
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_gift: Option<bool>,
+    /// Whether someone else will pay for the order
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pay_for_me: Option<bool>,
+}
+
+/// Optional parameters to filter the orders returned by the list orders endpoints.
+/// Use the `ListOrdersFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListOrdersFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// A timestamp from which to start listing orders e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing orders e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+}
+
+/// This struct represents the data of an order.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct OrderResponseData {
+    /// ID of the order
+    pub id: u64,
+    /// Code of the order
+    pub order_code: String,
+    /// Amount of the order, in the subunit of the currency
+    pub amount: u64,
+    /// Currency of the order
+    pub currency: Currency,
+    /// Status of the order
+    pub status: Option<String>,
+    /// Whether the order has been paid
+    pub paid: Option<bool>,
+    /// Time the order was paid
+    pub paid_at: Option<String>,
+    /// Customer of the order
+    pub customer: Option<CustomerReference>,
+    /// Products in the order
+    pub items: Option<Vec<Value>>,
+    /// Shipping details of the order
+    pub shipping: Option<Value>,
+    /// Shipping fees of the order, in the subunit of the currency
+    pub shipping_fees: Option<u64>,
+    /// Whether the order is a gift
+    pub is_gift: Option<bool>,
+    /// Whether someone else will pay for the order
+    pub pay_for_me: Option<bool>,
+    /// Discounts applied to the order
+    pub discounts: Option<Vec<Value>>,
+    /// Storefront the order was made on
+    pub storefront: Option<u64>,
+    /// Integration the order belongs to
+    pub integration: Option<u64>,
+    /// Domain of the order
+    pub domain: Option<Domain>,
+    /// Creation time of the order
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    /// Last update time of the order
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn can_create_order_request_with_builder() -> Result<(), Box<dyn Error>> {
+        let order = OrderRequestBuilder::default()
+            .email("customer@example.com".to_string())
+            .first_name("Ada".to_string())
+            .last_name("Obi".to_string())
+            .currency(Currency::NGN)
+            .items(vec![OrderItem {
+                product: 2196244,
+                quantity: 1,
+                amount: "10000".to_string(),
+            }])
+            .build()?;
+
+        let body = serde_json::to_value(order)?;
+        assert_eq!(body["items"][0]["product"], 2196244);
+        assert!(body.get("pay_for_me").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn cannot_create_order_request_without_items() {
+        let order = OrderRequestBuilder::default()
+            .email("customer@example.com".to_string())
+            .first_name("Ada".to_string())
+            .last_name("Obi".to_string())
+            .currency(Currency::NGN)
+            .build();
+
+        assert!(order.is_err());
+    }
+}
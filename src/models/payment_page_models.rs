@@ -0,0 +1,254 @@
+//! Payment Pages
+//! =============
+//! This file contains the models and options for the Payment Pages endpoint of the Paystack API.
+
+use std::fmt;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Currency, Domain, PlanResponseData, ProductResponseData, TransactionSplitResponseData,
+};
+
+/// Represents the type of a payment page.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum PaymentPageType {
+    /// A page for one-off payments, optionally with a fixed amount
+    #[default]
+    Payment,
+    /// A page for subscribing to a plan
+    Subscription,
+    /// A page for selling products
+    Product,
+    /// A page for paying for a plan
+    Plan,
+}
+
+impl fmt::Display for PaymentPageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let page_type = match self {
+            PaymentPageType::Payment => "payment",
+            PaymentPageType::Subscription => "subscription",
+            PaymentPageType::Product => "product",
+            PaymentPageType::Plan => "plan",
+        };
+        write!(f, "{page_type}")
+    }
+}
+
+/// This struct is used to create the body for creating a payment page.
+/// Use the `PaymentPageRequestBuilder` to create this object.
+///
+/// A fixed amount page sets `amount`, a plan page is linked with `plan` (or `linked_plan`)
+/// and a product page sets `page_type` to `PaymentPageType::Product`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct PaymentPageRequest {
+    /// Name of page
+    pub name: String,
+    /// A description for this page
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Amount should be in the subunit of the supported currency
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    /// Currency of the amount on the page
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    /// URL slug you would like to be associated with this page. Page will be accessible at `https://paystack.com/pay/[slug]`
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// The type of payment page to create. Defaults to `payment`
+    #[builder(setter(strip_option), default)]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub page_type: Option<PaymentPageType>,
+    /// The ID of the plan to subscribe customers on this payment page to
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<String>,
+    /// Specifies whether to collect a fixed amount on the payment page.
+    /// If true, `amount` must be passed.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_amount: Option<bool>,
+    /// The split code of the transaction split
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_code: Option<String>,
+    /// Extra data to configure the payment page including subaccount, logo image, transaction charge
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
+    /// If you would like Paystack to redirect someplace upon successful payment, specify the URL here.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_url: Option<String>,
+    /// A success message to display to the customer after a successful transaction
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success_message: Option<String>,
+    /// An email address that will receive transaction notifications for this payment page
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_email: Option<String>,
+    /// Specify whether to collect phone numbers on the payment page
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collect_phone: Option<bool>,
+    /// If you would like to accept custom fields, specify them here.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<Value>>,
+}
+
+impl PaymentPageRequestBuilder {
+    /// Links the page to a plan, turning it into a subscription page.
+    pub fn linked_plan(&mut self, plan: &PlanResponseData) -> &mut Self {
+        self.plan(plan.id.to_string())
+            .page_type(PaymentPageType::Subscription)
+    }
+
+    /// Links the page to a transaction split, so payments on the page are split.
+    pub fn linked_split(&mut self, split: &TransactionSplitResponseData) -> &mut Self {
+        self.split_code(split.split_code.clone())
+    }
+}
+
+/// This struct is used to create the body for updating a payment page.
+/// Use the `PaymentPageUpdateRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct PaymentPageUpdateRequest {
+    /// Name of page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A description for this page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Default amount you want to accept using this page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    /// Set to `false` to deactivate page url
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+}
+
+/// Optional parameters to filter the payment pages returned by the list payment pages endpoint.
+/// Use the `ListPaymentPagesFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListPaymentPagesFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// A timestamp from which to start listing pages e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing pages e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+}
+
+/// This struct represents the data of a payment page.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PaymentPageResponseData {
+    /// ID of the page
+    pub id: u64,
+    /// Name of the page
+    pub name: String,
+    /// Description of the page
+    pub description: Option<String>,
+    /// Integration the page belongs to
+    pub integration: Option<u64>,
+    /// Domain of the page
+    pub domain: Option<Domain>,
+    /// URL slug of the page
+    pub slug: String,
+    /// Amount of the page, in the subunit of the currency
+    pub amount: Option<u64>,
+    /// Currency of the page
+    pub currency: Option<String>,
+    /// Type of the page
+    #[serde(rename = "type")]
+    pub page_type: Option<PaymentPageType>,
+    /// ID of the plan linked to the page
+    pub plan: Option<u64>,
+    /// Whether the page collects a fixed amount
+    pub fixed_amount: Option<bool>,
+    /// Split code linked to the page
+    pub split_code: Option<String>,
+    /// URL to redirect to upon successful payment
+    pub redirect_url: Option<String>,
+    /// Message displayed after a successful payment
+    pub success_message: Option<String>,
+    /// Email address that receives transaction notifications for the page
+    pub notification_email: Option<String>,
+    /// Whether phone numbers are collected on the page
+    pub collect_phone: Option<bool>,
+    /// Whether the page is active
+    pub active: Option<bool>,
+    /// Whether the page is published
+    pub published: Option<bool>,
+    /// Metadata of the page
+    pub metadata: Option<Value>,
+    /// Products on the page
+    pub products: Option<Vec<ProductResponseData>>,
+    /// Creation time of the page
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    /// Last update time of the page
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn can_create_fixed_amount_payment_page() -> Result<(), Box<dyn Error>> {
+        let page = PaymentPageRequestBuilder::default()
+            .name("Buttercup Brunch".to_string())
+            .amount("500000".to_string())
+            .fixed_amount(true)
+            .build()?;
+
+        let body = serde_json::to_value(page)?;
+        assert_eq!(body["amount"], "500000");
+        assert!(body.get("type").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_link_payment_page_to_plan_and_split() -> Result<(), Box<dyn Error>> {
+        let plan = PlanResponseData {
+            id: 28,
+            ..Default::default()
+        };
+        let split = TransactionSplitResponseData {
+            split_code: "SPL_e7jnRLtzla".to_string(),
+            ..Default::default()
+        };
+
+        let page = PaymentPageRequestBuilder::default()
+            .name("Monthly Retainer".to_string())
+            .linked_plan(&plan)
+            .linked_split(&split)
+            .build()?;
+
+        let body = serde_json::to_value(page)?;
+        assert_eq!(body["plan"], "28");
+        assert_eq!(body["type"], "subscription");
+        assert_eq!(body["split_code"], "SPL_e7jnRLtzla");
+
+        Ok(())
+    }
+}
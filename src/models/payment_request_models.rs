@@ -0,0 +1,348 @@
+//! Payment Requests
+//! ================
+//! This file contains the models and options for the Payment Requests endpoint of the Paystack API.
+
+use std::fmt;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::{Currency, CustomerReference, Domain};
+
+/// Serializes the customer of a payment request body as its identifier
+fn serialize_customer_identifier<S: Serializer>(
+    customer: &CustomerReference,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&customer.identifier())
+}
+
+/// Serializes the optional customer of a payment request body as its identifier
+fn serialize_optional_customer_identifier<S: Serializer>(
+    customer: &Option<CustomerReference>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match customer {
+        Some(customer) => serialize_customer_identifier(customer, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// A line item of a payment request.
+/// Use the `LineItemBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct LineItem {
+    /// Name of the item
+    pub name: String,
+    /// Amount of the item, in the subunit of the currency
+    pub amount: String,
+    /// Quantity of the item. Defaults to 1
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u32>,
+}
+
+/// A tax applied to a payment request.
+/// Use the `TaxBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct Tax {
+    /// Name of the tax
+    pub name: String,
+    /// Amount of the tax, in the subunit of the currency
+    pub amount: String,
+}
+
+/// This struct is used to create the body for creating a payment request.
+/// Use the `CreatePaymentRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Default, Builder)]
+pub struct CreatePaymentRequest {
+    /// Customer code, or the customer, to send the payment request to
+    #[builder(setter(into))]
+    #[serde(serialize_with = "serialize_customer_identifier")]
+    pub customer: CustomerReference,
+    /// Payment request amount, in the subunit of the currency.
+    /// Only useful if line items and tax values are ignored.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    /// ISO 8601 representation of request due date
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+    /// A short description of the payment request
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Line items of the payment request
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<LineItem>>,
+    /// Taxes applied to the payment request
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax: Option<Vec<Tax>>,
+    /// Currency of the payment request. Defaults to `NGN`
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    /// Indicates whether Paystack sends an email notification to the customer. Defaults to `true`
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_notification: Option<bool>,
+    /// Indicate if request should be saved as draft. Defaults to `false`
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft: Option<bool>,
+    /// Set to `true` to create a draft invoice (adds an auto incrementing invoice number)
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_invoice: Option<bool>,
+    /// Numeric value of invoice. Invoice will start from 1 and auto increment from there
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_number: Option<u64>,
+    /// The split code of the transaction split
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_code: Option<String>,
+}
+
+/// This struct is used to create the body for updating a payment request.
+/// All fields are optional. Use the `UpdatePaymentRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Default, Builder)]
+#[builder(default)]
+pub struct UpdatePaymentRequest {
+    /// Customer code, or the customer, to send the payment request to
+    #[builder(setter(into, strip_option))]
+    #[serde(
+        serialize_with = "serialize_optional_customer_identifier",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub customer: Option<CustomerReference>,
+    /// Payment request amount, in the subunit of the currency
+    #[builder(setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    /// ISO 8601 representation of request due date
+    #[builder(setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+    /// A short description of the payment request
+    #[builder(setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Line items of the payment request
+    #[builder(setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<LineItem>>,
+    /// Taxes applied to the payment request
+    #[builder(setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax: Option<Vec<Tax>>,
+    /// Currency of the payment request
+    #[builder(setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    /// Indicates whether Paystack sends an email notification to the customer
+    #[builder(setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_notification: Option<bool>,
+    /// Indicate if request should be saved as draft
+    #[builder(setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft: Option<bool>,
+    /// Numeric value of invoice
+    #[builder(setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_number: Option<u64>,
+    /// The split code of the transaction split
+    #[builder(setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_code: Option<String>,
+}
+
+/// Optional parameters to filter the payment requests returned by the list payment requests endpoint.
+/// Use the `ListPaymentRequestsFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListPaymentRequestsFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// Filter by customer ID
+    pub customer: Option<u64>,
+    /// Filter by payment request status
+    pub status: Option<PaymentRequestStatus>,
+    /// Filter by currency
+    pub currency: Option<Currency>,
+    /// Show archived payment requests
+    pub include_archive: Option<bool>,
+    /// A timestamp from which to start listing payment requests e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing payment requests e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+}
+
+/// Represents the status of a payment request.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum PaymentRequestStatus {
+    /// The payment request is a draft and has not been sent
+    Draft,
+    /// The payment request is awaiting payment
+    #[default]
+    Pending,
+    /// The payment request has been paid
+    Success,
+    /// Payment of the payment request failed
+    Failed,
+}
+
+impl fmt::Display for PaymentRequestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            PaymentRequestStatus::Draft => "draft",
+            PaymentRequestStatus::Pending => "pending",
+            PaymentRequestStatus::Success => "success",
+            PaymentRequestStatus::Failed => "failed",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// This struct represents the data of a payment request.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PaymentRequestResponseData {
+    /// ID of the payment request
+    pub id: u64,
+    /// Integration the payment request belongs to
+    pub integration: Option<u64>,
+    /// Domain of the payment request
+    pub domain: Option<Domain>,
+    /// Amount of the payment request, in the subunit of the currency
+    pub amount: Option<u64>,
+    /// Currency of the payment request
+    pub currency: Option<String>,
+    /// Due date of the payment request
+    pub due_date: Option<String>,
+    /// Whether the payment request has an invoice number
+    pub has_invoice: Option<bool>,
+    /// Invoice number of the payment request
+    pub invoice_number: Option<u64>,
+    /// Description of the payment request
+    pub description: Option<String>,
+    /// URL of the PDF of the payment request
+    pub pdf_url: Option<String>,
+    /// Line items of the payment request
+    pub line_items: Option<Vec<Value>>,
+    /// Taxes applied to the payment request
+    pub tax: Option<Vec<Value>>,
+    /// Code of the payment request
+    pub request_code: String,
+    /// Status of the payment request
+    pub status: PaymentRequestStatus,
+    /// Whether the payment request has been paid
+    pub paid: Option<bool>,
+    /// Time the payment request was paid
+    pub paid_at: Option<String>,
+    /// Metadata of the payment request
+    pub metadata: Option<Value>,
+    /// Notifications sent for the payment request
+    pub notifications: Option<Vec<Value>>,
+    /// Reference to use when paying offline
+    pub offline_reference: Option<String>,
+    /// Customer of the payment request
+    pub customer: CustomerReference,
+    /// Whether the payment request has been archived
+    pub archived: Option<bool>,
+    /// Transactions made on the payment request, returned when verifying a payment request
+    pub transactions: Option<Vec<Value>>,
+    /// Creation time of the payment request
+    pub created_at: Option<String>,
+}
+
+/// Amount of payment requests in a currency.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CurrencyAmount {
+    /// Currency of the amount
+    pub currency: String,
+    /// Amount, in the subunit of the currency
+    pub amount: u64,
+}
+
+/// This struct represents the totals of the payment requests on the integration.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PaymentRequestTotalsData {
+    /// Amounts of pending payment requests per currency
+    pub pending: Vec<CurrencyAmount>,
+    /// Amounts of successful payment requests per currency
+    pub successful: Vec<CurrencyAmount>,
+    /// Total amounts of payment requests per currency
+    pub total: Vec<CurrencyAmount>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CustomerResponseData;
+    use std::error::Error;
+
+    #[test]
+    fn can_create_payment_request_with_line_items_and_tax() -> Result<(), Box<dyn Error>> {
+        let line_item = LineItemBuilder::default()
+            .name("Tripod stand".to_string())
+            .amount("2000000".to_string())
+            .quantity(2)
+            .build()?;
+        let tax = TaxBuilder::default()
+            .name("VAT".to_string())
+            .amount("2000".to_string())
+            .build()?;
+
+        let request = CreatePaymentRequestBuilder::default()
+            .customer("CUS_xwaj0txjryg393b")
+            .line_items(vec![line_item])
+            .tax(vec![tax])
+            .draft(true)
+            .build()?;
+
+        let body = serde_json::to_value(request)?;
+        assert_eq!(body["customer"], "CUS_xwaj0txjryg393b");
+        assert_eq!(body["line_items"][0]["quantity"], 2);
+        assert_eq!(body["tax"][0]["name"], "VAT");
+        assert!(body.get("due_date").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_create_payment_request_with_customer_data() -> Result<(), Box<dyn Error>> {
+        let customer: CustomerResponseData = serde_json::from_value(serde_json::json!({
+            "id": 1173,
+            "email": "customer@email.com",
+            "customer_code": "CUS_xwaj0txjryg393b"
+        }))?;
+
+        let request = CreatePaymentRequestBuilder::default()
+            .customer(customer)
+            .amount("42000".to_string())
+            .build()?;
+
+        let body = serde_json::to_value(request)?;
+        assert_eq!(body["customer"], "CUS_xwaj0txjryg393b");
+
+        Ok(())
+    }
+
+    #[test]
+    fn cannot_create_payment_request_without_customer() {
+        let request = CreatePaymentRequestBuilder::default()
+            .amount("42000".to_string())
+            .build();
+
+        assert!(request.is_err());
+    }
+}
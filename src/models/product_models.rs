@@ -0,0 +1,174 @@
+//! Products
+//! ========
+//! This file contains the models and options for the Products endpoint of the Paystack API.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Currency, Domain};
+
+/// Request body to create a product on your integration.
+/// Should be created via `ProductRequestBuilder`
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct ProductRequest {
+    /// Name of product
+    pub name: String,
+    /// A description for this product
+    pub description: String,
+    /// Price should be in the subunit of the supported currency
+    pub price: String,
+    /// Currency in which price is set
+    pub currency: Currency,
+    /// Set to `true` if the product has unlimited stock. Leave as `false` if the product has limited stock
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unlimited: Option<bool>,
+    /// Number of products in stock. Use if `unlimited` is `false`
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u32>,
+}
+
+/// Request body to update a product on your integration.
+/// Should be created via `ProductUpdateRequestBuilder`
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ProductUpdateRequest {
+    /// Name of product
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A description for this product
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Price should be in the subunit of the supported currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    /// Currency in which price is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    /// Set to `true` if the product has unlimited stock
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unlimited: Option<bool>,
+    /// Number of products in stock. Use if `unlimited` is `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u32>,
+}
+
+/// Optional parameters to filter the products returned by the list products endpoint.
+/// Use the `ListProductsFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListProductsFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// A timestamp from which to start listing products e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing products e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+}
+
+/// An image of a product.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ProductImage {
+    /// Storage key of the image
+    pub key: Option<String>,
+    /// URL of the image
+    pub path: Option<String>,
+    /// Media type of the image e.g. `image`
+    #[serde(rename = "type")]
+    pub image_type: Option<String>,
+    /// Original name of the uploaded image
+    pub original_filename: Option<String>,
+}
+
+/// This struct represents the data of a product.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ProductResponseData {
+    /// ID of the product
+    pub id: u64,
+    /// Name of the product
+    pub name: String,
+    /// Description of the product
+    pub description: Option<String>,
+    /// Code of the product
+    pub product_code: Option<String>,
+    /// Slug of the product
+    pub slug: Option<String>,
+    /// Price of the product, in the subunit of the currency
+    pub price: u64,
+    /// Currency in which price is set
+    pub currency: Currency,
+    /// Number of products in stock
+    pub quantity: Option<u32>,
+    /// Number of products sold
+    pub quantity_sold: Option<u32>,
+    /// Whether the product has unlimited stock
+    pub unlimited: bool,
+    /// Whether the product is in stock
+    pub in_stock: Option<bool>,
+    /// Whether the product is active
+    pub active: Option<bool>,
+    /// Whether the product can be shipped
+    pub is_shippable: Option<bool>,
+    /// Images of the product
+    pub files: Option<Vec<ProductImage>>,
+    /// URL of the main image of the product
+    pub image_path: Option<String>,
+    /// Metadata of the product
+    pub metadata: Option<Value>,
+    /// Integration the product belongs to
+    pub integration: Option<u64>,
+    /// Domain of the product
+    pub domain: Option<Domain>,
+    /// Creation time of the product
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    /// Last update time of the product
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn can_create_product_request_with_builder() -> Result<(), Box<dyn Error>> {
+        let product = ProductRequestBuilder::default()
+            .name("Puff Puff".to_string())
+            .description("Crispy flour ball with fluffy interior".to_string())
+            .price("5000".to_string())
+            .currency(Currency::NGN)
+            .unlimited(false)
+            .quantity(100)
+            .build()?;
+
+        let body = serde_json::to_value(product)?;
+        assert_eq!(body["currency"], "NGN");
+        assert_eq!(body["quantity"], 100);
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_parse_product_with_images() {
+        let product: ProductResponseData = serde_json::from_value(serde_json::json!({
+            "id": 526,
+            "name": "Puff Puff",
+            "price": 5000,
+            "currency": "NGN",
+            "unlimited": false,
+            "quantity": 100,
+            "files": [{"key": "product-images/puff.png", "path": "https://files.paystack.co/puff.png", "type": "image"}]
+        }))
+        .expect("unable to parse product");
+
+        assert_eq!(product.currency, Currency::NGN);
+        let files = product.files.unwrap();
+        assert_eq!(files[0].image_type.as_deref(), Some("image"));
+    }
+}
@@ -0,0 +1,168 @@
+//! Refunds
+//! =======
+//! This file contains the models and options for the Refunds endpoint of the Paystack API.
+
+use std::fmt;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Currency, Domain};
+
+/// This struct is used to create the body for creating a refund on your integration.
+/// Use the `RefundRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct RefundRequest {
+    /// Transaction reference or id
+    pub transaction: String,
+    /// Amount to be refunded to the customer, in the subunit of the currency.
+    /// Leave empty for a full refund.
+    /// It cannot be more than the original transaction amount
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    /// Currency of the refund
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    /// Customer reason
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_note: Option<String>,
+    /// Merchant reason
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_note: Option<String>,
+}
+
+/// Optional parameters to filter the refunds returned by the list refunds endpoint.
+/// Use the `ListRefundsFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListRefundsFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// The transaction ID or reference to list refunds for
+    pub transaction: Option<String>,
+    /// Filter refunds by currency
+    pub currency: Option<Currency>,
+    /// A timestamp from which to start listing refunds e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing refunds e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+}
+
+/// Represents the status of a refund.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum RefundStatus {
+    /// Refund initiated, waiting for response from the processor
+    #[default]
+    Pending,
+    /// Refund has been received by the processor
+    Processing,
+    /// Refund has successfully been processed by the processor
+    Processed,
+    /// Refund cannot be processed
+    Failed,
+}
+
+impl fmt::Display for RefundStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            RefundStatus::Pending => "pending",
+            RefundStatus::Processing => "processing",
+            RefundStatus::Processed => "processed",
+            RefundStatus::Failed => "failed",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// This struct represents the data of a refund.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RefundResponseData {
+    /// ID of the refund
+    pub id: u64,
+    /// Integration ID of the refund
+    pub integration: Option<u64>,
+    /// Domain of the refund
+    pub domain: Option<Domain>,
+    /// The refunded transaction. This is the transaction object when creating a refund
+    /// and the transaction ID when fetching or listing refunds.
+    pub transaction: Option<Value>,
+    /// Dispute ID, if the refund is the result of a dispute
+    pub dispute: Option<Value>,
+    /// Amount refunded in the subunit of the currency
+    pub amount: u64,
+    /// Amount already deducted from your balance, in the subunit of the currency
+    pub deducted_amount: Option<u64>,
+    /// If the full amount has been deducted from your balance
+    pub fully_deducted: Option<bool>,
+    /// Currency of the refund
+    pub currency: Currency,
+    /// Channel of the refunded transaction
+    pub channel: Option<String>,
+    /// Status of the refund
+    pub status: RefundStatus,
+    /// Email of the user who initiated the refund
+    pub refunded_by: Option<String>,
+    /// Time the refund was processed
+    pub refunded_at: Option<String>,
+    /// Time the refund is expected to be processed
+    pub expected_at: Option<String>,
+    /// Customer reason
+    pub customer_note: Option<String>,
+    /// Merchant reason
+    pub merchant_note: Option<String>,
+    /// Creation time of the refund
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    /// Last update time of the refund
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn can_create_partial_refund_request_with_builder() -> Result<(), Box<dyn Error>> {
+        let refund = RefundRequestBuilder::default()
+            .transaction("1641".to_string())
+            .amount("5000".to_string())
+            .merchant_note("Item out of stock".to_string())
+            .build()?;
+
+        let body = serde_json::to_value(refund)?;
+        assert_eq!(body["transaction"], "1641");
+        assert_eq!(body["amount"], "5000");
+        assert!(body.get("customer_note").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn cannot_create_refund_request_without_transaction() {
+        let refund = RefundRequestBuilder::default()
+            .amount("5000".to_string())
+            .build();
+
+        assert!(refund.is_err());
+    }
+
+    #[test]
+    fn can_use_refund_status() {
+        let status: RefundStatus =
+            serde_json::from_str("\"processed\"").expect("unable to parse refund status");
+
+        assert_eq!(status, RefundStatus::Processed);
+        assert_eq!(RefundStatus::Processing.to_string(), "processing");
+    }
+}
@@ -0,0 +1,144 @@
+//! Settlements
+//! ===========
+//! This file contains the models and options for the Settlements endpoint of the Paystack API.
+
+use std::fmt;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{Currency, Domain, SubaccountsResponseData};
+
+/// Represents the status of a settlement.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum SettlementStatus {
+    /// The settlement has been paid out
+    Success,
+    /// The settlement is being paid out
+    Processing,
+    /// The settlement is yet to be paid out
+    #[default]
+    Pending,
+    /// The settlement could not be paid out
+    Failed,
+}
+
+impl fmt::Display for SettlementStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            SettlementStatus::Success => "success",
+            SettlementStatus::Processing => "processing",
+            SettlementStatus::Pending => "pending",
+            SettlementStatus::Failed => "failed",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// Optional parameters to filter the settlements returned by the list settlements endpoint.
+/// Use the `ListSettlementsFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListSettlementsFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// Filter settlements by status
+    pub status: Option<SettlementStatus>,
+    /// Provide a subaccount ID to export only settlements for that subaccount.
+    /// Set to `none` to export only transactions for the account.
+    pub subaccount: Option<String>,
+    /// A timestamp from which to start listing settlements e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing settlements e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+}
+
+/// Optional parameters to filter the transactions returned by the list settlement transactions endpoint.
+/// Use the `ListSettlementTransactionsFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListSettlementTransactionsFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// A timestamp from which to start listing transactions e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing transactions e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+}
+
+/// This struct represents the data of a settlement.
+///
+/// Like `TransactionStatusData`, amounts are in the lowest denomination of the currency
+/// e.g. Kobo for NGN and cent for USD.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SettlementData {
+    /// ID of the settlement
+    pub id: u64,
+    /// Domain of the settlement
+    pub domain: Option<Domain>,
+    /// Status of the settlement
+    pub status: SettlementStatus,
+    /// Currency of the settlement
+    pub currency: Currency,
+    /// Integration the settlement belongs to
+    pub integration: Option<u64>,
+    /// Amount paid out after fees and deductions
+    pub total_amount: u32,
+    /// Amount paid out after deductions
+    pub effective_amount: Option<u32>,
+    /// Fees charged on the settled transactions
+    pub total_fees: Option<u32>,
+    /// Total amount of the settled transactions
+    pub total_processed: Option<u32>,
+    /// Amount deducted from the settlement e.g. for refunds or chargebacks
+    pub deductions: Option<u32>,
+    /// Date the settlement was paid out
+    pub settlement_date: Option<String>,
+    /// Who the settlement was paid out by
+    pub settled_by: Option<String>,
+    /// Subaccount the settlement was paid to
+    pub subaccount: Option<SubaccountsResponseData>,
+    /// Creation time of the settlement
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    /// Last update time of the settlement
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_settlement() {
+        let settlement: SettlementData = serde_json::from_value(serde_json::json!({
+            "id": 3090024,
+            "domain": "live",
+            "status": "success",
+            "currency": "NGN",
+            "integration": 463433,
+            "total_amount": 52800,
+            "effective_amount": 52800,
+            "total_fees": 2200,
+            "total_processed": 55000,
+            "deductions": null,
+            "settlement_date": "2022-11-05T00:00:00.000Z",
+            "settled_by": null,
+            "createdAt": "2022-11-04T09:35:35.000Z",
+            "updatedAt": "2022-11-05T08:40:57.000Z"
+        }))
+        .expect("unable to parse settlement");
+
+        assert_eq!(settlement.status, SettlementStatus::Success);
+        assert_eq!(settlement.currency, Currency::NGN);
+        assert_eq!(settlement.total_amount, 52800);
+        assert!(settlement.subaccount.is_none());
+    }
+}
@@ -0,0 +1,110 @@
+//! Storefronts
+//! ===========
+//! This file contains the models and options for the Storefronts endpoint of the Paystack API.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Currency, Domain, ProductResponseData};
+
+/// Request body to create a storefront on your integration.
+/// Should be created via `StorefrontRequestBuilder`
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct StorefrontRequest {
+    /// Name of the storefront
+    pub name: String,
+    /// A unique identifier to access your store. Once the storefront is created, it can be accessed from `https://paystack.shop/your-slug`
+    pub slug: String,
+    /// Currency for prices of products in your storefront
+    pub currency: Currency,
+    /// The description of the storefront
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Request body to update a storefront on your integration.
+/// Should be created via `StorefrontUpdateRequestBuilder`
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct StorefrontUpdateRequest {
+    /// Name of the storefront
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A unique identifier to access your store
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// The description of the storefront
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Optional parameters to filter the storefronts returned by the list storefronts endpoint.
+/// Use the `ListStorefrontsFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListStorefrontsFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// Filter storefronts by status e.g. `active` or `inactive`
+    pub status: Option<String>,
+}
+
+/// This struct represents the data of a storefront.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct StorefrontResponseData {
+    /// ID of the storefront
+    pub id: u64,
+    /// Name of the storefront
+    pub name: String,
+    /// Slug of the storefront
+    pub slug: String,
+    /// Description of the storefront
+    pub description: Option<String>,
+    /// Currency of the products in the storefront
+    pub currency: Currency,
+    /// Status of the storefront
+    pub status: Option<String>,
+    /// Integration the storefront belongs to
+    pub integration: Option<u64>,
+    /// Domain of the storefront
+    pub domain: Option<Domain>,
+    /// Products in the storefront
+    pub products: Option<Vec<ProductResponseData>>,
+    /// Contact details of the storefront
+    pub contacts: Option<Vec<Value>>,
+    /// Social media accounts of the storefront
+    pub social_media: Option<Vec<Value>>,
+    /// Shipping fees of the storefront
+    pub shipping_fees: Option<Vec<Value>>,
+    /// Creation time of the storefront
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    /// Last update time of the storefront
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn can_create_storefront_request_with_builder() -> Result<(), Box<dyn Error>> {
+        let storefront = StorefrontRequestBuilder::default()
+            .name("Sneakers Hub".to_string())
+            .slug("sneakers-hub".to_string())
+            .currency(Currency::NGN)
+            .build()?;
+
+        let body = serde_json::to_value(storefront)?;
+        assert_eq!(body["currency"], "NGN");
+        assert!(body.get("description").is_none());
+
+        Ok(())
+    }
+}
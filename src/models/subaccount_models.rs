@@ -75,7 +75,7 @@ pub struct SubaccountData {
 }
 
 /// Data of the list Subaccount response
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct SubaccountsResponseData {
     /// Integration ID of subaccount.
     pub integration: Option<u32>,
@@ -1,46 +1,204 @@
+//! Subscriptions
+//! =============
+//! This file contains the models and options for the Subscriptions endpoint of the Paystack API.
+
 use std::fmt;
 
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::{Authorization, Domain};
+use crate::{Authorization, CustomerReference, Domain, PlanResponseData};
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct Subscription {
-    pub customer: u32,
-    pub plan: u32,
+    /// The subscribed customer. This is the customer ID when nested in other objects
+    /// and the customer object when listing or fetching subscriptions.
+    pub customer: CustomerReference,
+    /// The plan subscribed to. This is the plan ID when nested in other objects
+    /// and the plan object when listing or fetching subscriptions.
+    pub plan: SubscriptionPlan,
     pub integration: u32,
     pub domain: Domain,
-    pub start: u32,
+    pub start: Option<u32>,
     pub status: SubscriptionStatus,
     pub quantity: u32,
     pub amount: u32,
     pub subscription_code: String,
+    /// Token used to enable or disable the subscription
     pub email_token: String,
     pub authorization: Authorization,
     pub easy_cron_id: Option<String>,
-    pub cron_expression: String,
-    pub next_payment_date: String,
+    pub cron_expression: Option<String>,
+    pub next_payment_date: Option<String>,
     pub open_invoice: Option<String>,
+    /// Number of times the customer will be charged
+    pub invoice_limit: Option<u32>,
+    /// Split code attached to the subscription
+    pub split_code: Option<String>,
+    /// Invoices raised for the subscription, returned when fetching a subscription
+    pub invoices: Option<Vec<Value>>,
     pub id: u32,
+    #[serde(rename = "cancelledAt")]
+    pub cancelled_at: Option<String>,
     #[serde(rename = "createdAt")]
     pub created_at: String,
     #[serde(rename = "updatedAt")]
     pub updated_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "lowercase")]
+/// The plan of a subscription, either as an ID or as the full plan object.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum SubscriptionPlan {
+    /// ID of the plan
+    Id(u64),
+    /// Full plan object
+    Plan(Box<PlanResponseData>),
+}
+
+impl Default for SubscriptionPlan {
+    fn default() -> Self {
+        SubscriptionPlan::Id(0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
 #[non_exhaustive]
 pub enum SubscriptionStatus {
+    /// The subscription is currently active, and will be charged on the next payment date.
+    Active,
+    /// The subscription is currently active, but won't be charged on the next payment date.
+    NonRenewing,
+    /// The subscription is still active, but there was an issue while trying to charge the customer's card.
+    Attention,
+    /// The subscription is complete, and will no longer be charged.
     #[default]
     Complete,
+    /// The subscription has been cancelled, and we'll no longer attempt to charge the customer on the next payment date.
+    Cancelled,
 }
 
 impl fmt::Display for SubscriptionStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let status = match self {
+            SubscriptionStatus::Active => "active",
+            SubscriptionStatus::NonRenewing => "non-renewing",
+            SubscriptionStatus::Attention => "attention",
             SubscriptionStatus::Complete => "complete",
+            SubscriptionStatus::Cancelled => "cancelled",
         };
         write!(f, "{status}")
     }
 }
+
+/// Request body to create a subscription on your integration.
+/// Should be created via `SubscriptionRequestBuilder`
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct SubscriptionRequest {
+    /// Customer's email address or customer code
+    pub customer: String,
+    /// Plan code
+    pub plan: String,
+    /// If customer has multiple authorizations, you can set the desired authorization you wish to use for this subscription here.
+    /// If this is not supplied, the customer's most recent authorization would be used
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<String>,
+    /// Set the date for the first debit. (ISO 8601 format) e.g. `2017-05-16T00:30:13+01:00`
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+}
+
+/// Optional parameters to filter the subscriptions returned by the list subscriptions endpoint.
+/// Use the `ListSubscriptionsFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListSubscriptionsFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// Filter by Customer ID
+    pub customer: Option<u64>,
+    /// Filter by Plan ID
+    pub plan: Option<u64>,
+}
+
+/// This struct represents the link to update the card on a subscription.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SubscriptionLinkData {
+    /// Link to update the card on the subscription
+    pub link: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn can_create_subscription_request_with_builder() -> Result<(), Box<dyn Error>> {
+        let subscription = SubscriptionRequestBuilder::default()
+            .customer("CUS_xnxdt6s1zg1f4nx".to_string())
+            .plan("PLN_gx2wn530m0i3w3m".to_string())
+            .build()?;
+
+        let body = serde_json::to_value(subscription)?;
+        assert_eq!(body["customer"], "CUS_xnxdt6s1zg1f4nx");
+        assert!(body.get("authorization").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_parse_subscription_status() {
+        let status: SubscriptionStatus =
+            serde_json::from_str("\"non-renewing\"").expect("unable to parse subscription status");
+
+        assert_eq!(status, SubscriptionStatus::NonRenewing);
+        assert_eq!(SubscriptionStatus::Complete.to_string(), "complete");
+    }
+
+    #[test]
+    fn can_parse_subscription_with_customer_and_plan_ids_or_objects() {
+        let with_ids = serde_json::json!({
+            "customer": 1173,
+            "plan": 28,
+            "integration": 100032,
+            "domain": "test",
+            "start": 1459296064,
+            "status": "active",
+            "quantity": 1,
+            "amount": 50000,
+            "authorization": {"authorization_code": "AUTH_6tmt288t0o"},
+            "subscription_code": "SUB_vsyqdmlzble3uii",
+            "email_token": "d7gofp6yppn3qz7",
+            "id": 9,
+            "createdAt": "2016-03-30T00:01:04.687Z",
+            "updatedAt": "2016-03-30T00:01:04.687Z"
+        });
+        let mut with_objects = with_ids.clone();
+        with_objects["customer"] = serde_json::json!({
+            "id": 1173,
+            "email": "customer@email.com",
+            "customer_code": "CUS_xnxdt6s1zg1f4nx"
+        });
+
+        let subscription: Subscription =
+            serde_json::from_value(with_ids).expect("unable to parse subscription");
+        assert!(matches!(subscription.customer, CustomerReference::Id(1173)));
+        assert!(matches!(subscription.plan, SubscriptionPlan::Id(28)));
+
+        let subscription: Subscription =
+            serde_json::from_value(with_objects).expect("unable to parse subscription");
+        match subscription.customer {
+            CustomerReference::Customer(customer) => {
+                assert_eq!(customer.customer_code, "CUS_xnxdt6s1zg1f4nx")
+            }
+            _ => panic!("expected a customer object"),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Transfers Control
+//! =================
+//! This file contains the models and options for the Transfers Control endpoint of the Paystack API.
+
+use std::fmt;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{Currency, Domain};
+
+/// This struct represents the balance of your integration in a single currency.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BalanceData {
+    /// Currency of the balance
+    pub currency: Currency,
+    /// Available balance in the subunit of the currency
+    pub balance: i64,
+}
+
+/// Optional parameters to filter the entries returned by the balance ledger endpoint.
+/// Use the `ListBalanceLedgerFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListBalanceLedgerFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// A timestamp from which to start listing entries e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing entries e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+}
+
+/// This struct represents an entry of the balance ledger of your integration.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BalanceLedgerData {
+    /// Integration ID of the entry
+    pub integration: Option<u64>,
+    /// Domain of the entry
+    pub domain: Option<Domain>,
+    /// Balance after the entry, in the subunit of the currency
+    pub balance: i64,
+    /// Currency of the entry
+    pub currency: Currency,
+    /// Change in balance caused by the entry, in the subunit of the currency
+    pub difference: i64,
+    /// Reason for the entry
+    pub reason: Option<String>,
+    /// The model responsible for the entry e.g. `Transaction` or `Transfer`
+    pub model_responsible: Option<String>,
+    /// ID of the record of the responsible model
+    pub model_row: Option<u64>,
+    /// ID of the entry
+    pub id: u64,
+    /// Creation time of the entry
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    /// Last update time of the entry
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+}
+
+/// The reason for resending the OTP of a transfer.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResendOtpReason {
+    /// Resend the OTP because the previous one was not received or has expired
+    #[default]
+    ResendOtp,
+    /// Resend the OTP of the transfer
+    Transfer,
+}
+
+impl fmt::Display for ResendOtpReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            ResendOtpReason::ResendOtp => "resend_otp",
+            ResendOtpReason::Transfer => "transfer",
+        };
+        write!(f, "{reason}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_balance_per_currency() {
+        let balances: Vec<BalanceData> = serde_json::from_str(
+            r#"[{"currency":"NGN","balance":123456},{"currency":"USD","balance":0}]"#,
+        )
+        .expect("unable to parse balances");
+
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[0].currency, Currency::NGN);
+        assert_eq!(balances[0].balance, 123456);
+        assert_eq!(balances[1].currency, Currency::USD);
+    }
+
+    #[test]
+    fn can_use_resend_otp_reason() {
+        assert_eq!(ResendOtpReason::ResendOtp.to_string(), "resend_otp");
+        assert_eq!(ResendOtpReason::Transfer.to_string(), "transfer");
+    }
+}
@@ -0,0 +1,257 @@
+//! Transfers
+//! =========
+//! This file contains the models and options for the Transfers endpoint of the Paystack API.
+
+use std::fmt;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::{Currency, Domain};
+
+/// Reads an integration sent either as an ID or as an object with an `id`
+fn deserialize_integration_id<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<u64>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Integration {
+        Id(u64),
+        Object { id: u64 },
+    }
+
+    let integration = Option::<Integration>::deserialize(deserializer)?;
+    Ok(integration.map(|integration| match integration {
+        Integration::Id(id) | Integration::Object { id } => id,
+    }))
+}
+
+/// This struct is used to create the body for initiating a transfer on your integration.
+/// Use the `TransferRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct TransferRequest {
+    /// Where should we transfer from? Only `balance` is supported for now
+    #[builder(default = "String::from(\"balance\")")]
+    pub source: String,
+    /// Amount to transfer in the subunit of the supported currency
+    pub amount: String,
+    /// Code for transfer recipient
+    pub recipient: String,
+    /// The reason for the transfer
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Specify the currency of the transfer. Defaults to NGN
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    /// A unique identifier containing lowercase letters (a-z), digits (0-9) and these symbols: dash (-), underscore (_).
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// A unique identifier required in Kenya for MPESA Paybill and Till transfers
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_reference: Option<String>,
+}
+
+/// This struct is used to create the body for initiating a bulk transfer on your integration.
+/// Use the `BulkTransferRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct BulkTransferRequest {
+    /// Where should we transfer from? Only `balance` is supported for now
+    #[builder(default = "String::from(\"balance\")")]
+    pub source: String,
+    /// Specify the currency of the transfers. Defaults to NGN
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    /// A list of transfer objects. Should be created with the `BulkTransferItemBuilder` struct.
+    pub transfers: Vec<BulkTransferItem>,
+}
+
+/// A single transfer in a bulk transfer request.
+/// Use the `BulkTransferItemBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct BulkTransferItem {
+    /// Amount to transfer in the subunit of the supported currency
+    pub amount: String,
+    /// Code for transfer recipient
+    pub recipient: String,
+    /// A unique identifier containing lowercase letters (a-z), digits (0-9) and these symbols: dash (-), underscore (_).
+    pub reference: String,
+    /// The reason for the transfer
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Optional parameters to filter the transfers returned by the list transfers endpoint.
+/// Use the `ListTransfersFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListTransfersFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// Filter by the recipient ID
+    pub recipient: Option<u64>,
+    /// A timestamp from which to start listing transfers e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing transfers e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+}
+
+/// Represents the status of a transfer.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum TransferStatus {
+    /// The transfer is waiting to be processed
+    #[default]
+    Pending,
+    /// The transfer is waiting for an OTP to be finalized
+    Otp,
+    /// The transfer has been received by the processor
+    Received,
+    /// The transfer was successful
+    Success,
+    /// The transfer failed
+    Failed,
+    /// The transfer was reversed
+    Reversed,
+    /// The transfer was abandoned
+    Abandoned,
+    /// The transfer was blocked
+    Blocked,
+    /// The transfer was rejected
+    Rejected,
+}
+
+impl fmt::Display for TransferStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            TransferStatus::Pending => "pending",
+            TransferStatus::Otp => "otp",
+            TransferStatus::Received => "received",
+            TransferStatus::Success => "success",
+            TransferStatus::Failed => "failed",
+            TransferStatus::Reversed => "reversed",
+            TransferStatus::Abandoned => "abandoned",
+            TransferStatus::Blocked => "blocked",
+            TransferStatus::Rejected => "rejected",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// This struct represents the data of a transfer.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TransferResponseData {
+    /// Integration ID of the transfer. Webhook events send the integration object,
+    /// which is read down to its ID.
+    #[serde(default, deserialize_with = "deserialize_integration_id")]
+    pub integration: Option<u64>,
+    /// Domain of the transfer
+    pub domain: Option<Domain>,
+    /// Amount of the transfer in the subunit of the currency
+    pub amount: u64,
+    /// Currency of the transfer
+    pub currency: Currency,
+    /// Source of the transfer, usually `balance`
+    pub source: Option<String>,
+    /// Reason for the transfer
+    pub reason: Option<String>,
+    /// Recipient of the transfer. This is the recipient ID when initiating a transfer
+    /// and the recipient object when fetching or listing transfers.
+    pub recipient: Option<Value>,
+    /// Status of the transfer
+    pub status: TransferStatus,
+    /// Code of the transfer
+    pub transfer_code: String,
+    /// Reference of the transfer
+    pub reference: Option<String>,
+    /// ID of the transfer
+    pub id: u64,
+    /// Reason for a failed transfer, if any
+    pub failures: Option<Value>,
+    /// Time the transfer was completed
+    pub transferred_at: Option<String>,
+    /// Creation time of the transfer
+    #[serde(rename = "createdAt", alias = "created_at")]
+    pub created_at: Option<String>,
+    /// Last update time of the transfer
+    #[serde(rename = "updatedAt", alias = "updated_at")]
+    pub updated_at: Option<String>,
+}
+
+/// This struct represents a transfer queued by the bulk transfer endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BulkTransferResponseData {
+    /// Reference of the transfer
+    pub reference: Option<String>,
+    /// Code of the transfer recipient
+    pub recipient: String,
+    /// Amount of the transfer in the subunit of the currency
+    pub amount: u64,
+    /// Code of the transfer
+    pub transfer_code: String,
+    /// Currency of the transfer
+    pub currency: Currency,
+    /// Status of the transfer
+    pub status: TransferStatus,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn can_create_transfer_request_with_builder() -> Result<(), Box<dyn Error>> {
+        let transfer = TransferRequestBuilder::default()
+            .amount("37800".to_string())
+            .recipient("RCP_t0ya41mp35flk40".to_string())
+            .reason("Discount Refund".to_string())
+            .build()?;
+
+        assert_eq!(transfer.source, "balance");
+        assert_eq!(transfer.amount, "37800");
+        assert_eq!(transfer.recipient, "RCP_t0ya41mp35flk40");
+        assert_eq!(transfer.currency, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cannot_create_transfer_request_without_compulsory_field() {
+        let transfer = TransferRequestBuilder::default()
+            .currency(Currency::NGN)
+            .build();
+
+        assert!(transfer.is_err());
+    }
+
+    #[test]
+    fn can_create_bulk_transfer_request_with_builder() -> Result<(), Box<dyn Error>> {
+        let item = BulkTransferItemBuilder::default()
+            .amount("20000".to_string())
+            .recipient("RCP_t0ya41mp35flk40".to_string())
+            .reference("acv_2627bbfe-1a2a-4a1a-8d0e-9d2ee6c31496".to_string())
+            .build()?;
+
+        let bulk = BulkTransferRequestBuilder::default()
+            .currency(Currency::NGN)
+            .transfers(vec![item])
+            .build()?;
+
+        let body = serde_json::to_value(bulk)?;
+        assert_eq!(body["source"], "balance");
+        assert_eq!(body["transfers"][0]["amount"], "20000");
+        assert!(body["transfers"][0].get("reason").is_none());
+
+        Ok(())
+    }
+}
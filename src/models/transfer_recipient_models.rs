@@ -0,0 +1,286 @@
+//! Transfer Recipients
+//! ===================
+//! This file contains the models and options for the Transfer Recipients endpoint of the Paystack API.
+
+use std::fmt;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Currency, Domain};
+
+/// Represents the different types of transfer recipients supported by Paystack.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecipientType {
+    /// Nigerian bank account (NUBAN)
+    #[default]
+    Nuban,
+    /// Ghanaian bank account (GhIPSS)
+    Ghipss,
+    /// Mobile money account, supported in Ghana and Kenya
+    MobileMoney,
+    /// South African bank account (BASA)
+    Basa,
+    /// A reusable card authorization
+    Authorization,
+}
+
+impl RecipientType {
+    /// The currencies a recipient of this type can receive transfers in.
+    ///
+    /// An empty slice means the recipient follows the currency of the authorization.
+    pub fn supported_currencies(&self) -> &'static [Currency] {
+        match self {
+            RecipientType::Nuban => &[Currency::NGN],
+            RecipientType::Ghipss => &[Currency::GHS],
+            RecipientType::MobileMoney => &[Currency::GHS, Currency::KES],
+            RecipientType::Basa => &[Currency::ZAR],
+            RecipientType::Authorization => &[],
+        }
+    }
+}
+
+impl fmt::Display for RecipientType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let recipient_type = match self {
+            RecipientType::Nuban => "nuban",
+            RecipientType::Ghipss => "ghipss",
+            RecipientType::MobileMoney => "mobile_money",
+            RecipientType::Basa => "basa",
+            RecipientType::Authorization => "authorization",
+        };
+        write!(f, "{recipient_type}")
+    }
+}
+
+/// This struct is used to create the body for creating a transfer recipient on your integration.
+/// Use the `TransferRecipientRequestBuilder` to create this object.
+///
+/// The builder checks that the fields match the recipient type: bank and mobile money recipients
+/// need an `account_number` and a `bank_code`, authorization recipients need an `authorization_code`
+/// and an `email`, and the `currency` must be supported by the recipient type.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct TransferRecipientRequest {
+    /// Recipient Type
+    #[serde(rename = "type")]
+    pub recipient_type: RecipientType,
+    /// The recipient's name according to their account registration.
+    pub name: String,
+    /// Recipient's bank account number
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+    /// Recipient's bank code. You can get the list of Bank Codes by calling the List Banks endpoint.
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_code: Option<String>,
+    /// A description for this recipient
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Currency for the account receiving the transfer
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    /// An authorization code from a previous transaction
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_code: Option<String>,
+    /// Email address of the recipient, required for the `authorization` type
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Stringified JSON object of custom data
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+}
+
+impl TransferRecipientRequestBuilder {
+    fn validate(&self) -> Result<(), String> {
+        let recipient_type = self.recipient_type.clone().unwrap_or_default();
+        let is_set = |field: &Option<Option<String>>| matches!(field, Some(Some(_)));
+
+        match recipient_type {
+            RecipientType::Authorization => {
+                if !is_set(&self.authorization_code) || !is_set(&self.email) {
+                    return Err(
+                        "authorization recipients require an authorization_code and an email"
+                            .to_string(),
+                    );
+                }
+            }
+            _ => {
+                if !is_set(&self.account_number) || !is_set(&self.bank_code) {
+                    return Err(format!(
+                        "{recipient_type} recipients require an account_number and a bank_code"
+                    ));
+                }
+            }
+        }
+
+        if let Some(Some(currency)) = &self.currency {
+            let supported = recipient_type.supported_currencies();
+            if !supported.is_empty() && !supported.contains(currency) {
+                return Err(format!(
+                    "{recipient_type} recipients cannot receive transfers in {currency}"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Optional parameters to filter the transfer recipients returned by the list endpoint.
+/// Use the `ListTransferRecipientsFilterBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct ListTransferRecipientsFilter {
+    /// Number of records to retrieve per page. Defaults to 50
+    pub per_page: Option<u32>,
+    /// Page number to retrieve. Defaults to 1
+    pub page: Option<u32>,
+    /// A timestamp from which to start listing transfer recipients e.g. `2016-09-24T00:00:05.000Z`
+    pub from: Option<String>,
+    /// A timestamp at which to stop listing transfer recipients e.g. `2016-09-24T00:00:05.000Z`
+    pub to: Option<String>,
+}
+
+/// This struct represents a transfer recipient.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TransferRecipientResponseData {
+    /// If the recipient is active or not
+    pub active: Option<bool>,
+    /// Currency of the recipient
+    pub currency: Currency,
+    /// Description of the recipient
+    pub description: Option<String>,
+    /// Domain of the recipient
+    pub domain: Option<Domain>,
+    /// Email address of the recipient
+    pub email: Option<String>,
+    /// ID of the recipient
+    pub id: u64,
+    /// Integration ID of the recipient
+    pub integration: Option<u64>,
+    /// Custom data attached to the recipient
+    pub metadata: Option<Value>,
+    /// Name of the recipient
+    pub name: String,
+    /// Code of the recipient, used when initiating transfers
+    pub recipient_code: String,
+    /// Type of the recipient
+    #[serde(rename = "type")]
+    pub recipient_type: RecipientType,
+    /// If the recipient has been deleted
+    pub is_deleted: Option<bool>,
+    /// Account details of the recipient
+    pub details: Option<TransferRecipientDetails>,
+    /// Creation time of the recipient
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    /// Last update time of the recipient
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+}
+
+/// The account details of a transfer recipient.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TransferRecipientDetails {
+    /// Authorization code of the recipient, for `authorization` recipients
+    pub authorization_code: Option<String>,
+    /// Account number of the recipient
+    pub account_number: Option<String>,
+    /// Account name of the recipient
+    pub account_name: Option<String>,
+    /// Bank code of the recipient
+    pub bank_code: Option<String>,
+    /// Bank name of the recipient
+    pub bank_name: Option<String>,
+}
+
+/// This struct represents the result of a bulk transfer recipient creation.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BulkTransferRecipientResponseData {
+    /// Recipients that were created
+    pub success: Vec<TransferRecipientResponseData>,
+    /// Recipients that could not be created, with the reason
+    pub errors: Vec<Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn can_create_nuban_recipient_with_builder() -> Result<(), Box<dyn Error>> {
+        let recipient = TransferRecipientRequestBuilder::default()
+            .recipient_type(RecipientType::Nuban)
+            .name("Tolu Robert".to_string())
+            .account_number("01000000010".to_string())
+            .bank_code("058".to_string())
+            .currency(Currency::NGN)
+            .build()?;
+
+        let body = serde_json::to_value(recipient)?;
+        assert_eq!(body["type"], "nuban");
+        assert_eq!(body["bank_code"], "058");
+        assert!(body.get("email").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_create_authorization_recipient_with_builder() -> Result<(), Box<dyn Error>> {
+        let recipient = TransferRecipientRequestBuilder::default()
+            .recipient_type(RecipientType::Authorization)
+            .name("Random Joe".to_string())
+            .email("random@joe.com".to_string())
+            .authorization_code("AUTH_ncx8hews93".to_string())
+            .build()?;
+
+        assert_eq!(recipient.recipient_type, RecipientType::Authorization);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cannot_create_bank_recipient_without_bank_code() {
+        let recipient = TransferRecipientRequestBuilder::default()
+            .recipient_type(RecipientType::Ghipss)
+            .name("Kofi Mensah".to_string())
+            .account_number("0123456789".to_string())
+            .build();
+
+        assert!(recipient.is_err());
+    }
+
+    #[test]
+    fn cannot_create_authorization_recipient_without_email() {
+        let recipient = TransferRecipientRequestBuilder::default()
+            .recipient_type(RecipientType::Authorization)
+            .name("Random Joe".to_string())
+            .authorization_code("AUTH_ncx8hews93".to_string())
+            .build();
+
+        assert!(recipient.is_err());
+    }
+
+    #[test]
+    fn cannot_create_recipient_with_unsupported_currency() {
+        let recipient = TransferRecipientRequestBuilder::default()
+            .recipient_type(RecipientType::Basa)
+            .name("Thabo Nkosi".to_string())
+            .account_number("0123456789".to_string())
+            .bank_code("632005".to_string())
+            .currency(Currency::NGN)
+            .build();
+
+        assert!(recipient.is_err());
+    }
+}
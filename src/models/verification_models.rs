@@ -0,0 +1,154 @@
+//! Verification
+//! ============
+//! This file contains the models and options for the Verification endpoints of the Paystack API.
+
+use std::fmt;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Represents the type of a bank account being validated.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AccountType {
+    /// An account owned by an individual
+    #[default]
+    Personal,
+    /// An account owned by a business
+    Business,
+}
+
+impl fmt::Display for AccountType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let account_type = match self {
+            AccountType::Personal => "personal",
+            AccountType::Business => "business",
+        };
+        write!(f, "{account_type}")
+    }
+}
+
+/// Represents the type of document used to validate a bank account.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum DocumentType {
+    /// National identity number of the account holder
+    #[default]
+    IdentityNumber,
+    /// Passport number of the account holder
+    PassportNumber,
+    /// Registration number of the business owning the account
+    BusinessRegistrationNumber,
+}
+
+impl fmt::Display for DocumentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let document_type = match self {
+            DocumentType::IdentityNumber => "identityNumber",
+            DocumentType::PassportNumber => "passportNumber",
+            DocumentType::BusinessRegistrationNumber => "businessRegistrationNumber",
+        };
+        write!(f, "{document_type}")
+    }
+}
+
+/// This struct is used to create the body for validating a bank account.
+/// Account validation is currently only supported for South African accounts.
+/// Use the `ValidateAccountRequestBuilder` to create this object.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct ValidateAccountRequest {
+    /// Customer's first and last name registered with their bank
+    pub account_name: String,
+    /// Customer's account number
+    pub account_number: String,
+    /// Type of the customer's account
+    pub account_type: AccountType,
+    /// Customer's bank code
+    pub bank_code: String,
+    /// The two digit ISO code of the customer's bank e.g. `ZA`
+    pub country_code: String,
+    /// Customer's mode of identity
+    pub document_type: DocumentType,
+    /// Customer's mode of identity number
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_number: Option<String>,
+}
+
+/// This struct represents a resolved bank account.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ResolveAccountData {
+    /// Account number that was resolved
+    pub account_number: String,
+    /// Name registered to the account
+    pub account_name: String,
+    /// ID of the bank of the account
+    pub bank_id: Option<u64>,
+}
+
+/// This struct represents the result of a bank account validation.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ValidateAccountData {
+    /// Whether the account details were verified
+    pub verified: bool,
+    /// Message explaining the verification result
+    #[serde(rename = "verificationMessage")]
+    pub verification_message: String,
+}
+
+/// This struct represents a resolved card BIN.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CardBinData {
+    /// First 6 digits of the card
+    pub bin: String,
+    /// Brand of the card e.g. `Mastercard`
+    pub brand: String,
+    /// Sub brand of the card
+    pub sub_brand: Option<String>,
+    /// Two digit ISO code of the country the card was issued in
+    pub country_code: Option<String>,
+    /// Name of the country the card was issued in
+    pub country_name: Option<String>,
+    /// Type of the card e.g. `DEBIT`
+    pub card_type: Option<String>,
+    /// Name of the bank that issued the card
+    pub bank: Option<String>,
+    /// ID of the bank that issued the card
+    pub linked_bank_id: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn can_create_validate_account_request_with_builder() -> Result<(), Box<dyn Error>> {
+        let request = ValidateAccountRequestBuilder::default()
+            .account_name("Ann Bron".to_string())
+            .account_number("0123456789".to_string())
+            .account_type(AccountType::Personal)
+            .bank_code("632005".to_string())
+            .country_code("ZA".to_string())
+            .document_type(DocumentType::IdentityNumber)
+            .document_number("1234567890123".to_string())
+            .build()?;
+
+        let body = serde_json::to_value(request)?;
+        assert_eq!(body["account_type"], "personal");
+        assert_eq!(body["document_type"], "identityNumber");
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_parse_validate_account_data() {
+        let data: ValidateAccountData = serde_json::from_value(serde_json::json!({
+            "verified": true,
+            "verificationMessage": "Account is verified successfully"
+        }))
+        .expect("unable to parse validation data");
+
+        assert!(data.verified);
+    }
+}
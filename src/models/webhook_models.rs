@@ -0,0 +1,256 @@
+//! Webhook Models
+//! ==============
+//! This file contains the typed events Paystack sends to your webhook URL.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Authorization, Currency, CustomerResponseData, DedicatedVirtualAccountResponseData,
+    DisputeResponseData, Domain, PaymentRequestResponseData, RefundStatus, Subscription,
+    TransactionStatusData, TransferResponseData,
+};
+
+/// The raw envelope of every webhook request sent by Paystack.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookPayload {
+    /// Name of the event e.g. `charge.success`
+    pub event: String,
+    /// Payload of the event, its shape depends on the event
+    pub data: Value,
+}
+
+/// Payload of the `dedicatedaccount.assign.*` events.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DedicatedAccountAssignmentData {
+    /// The customer the account was assigned to
+    pub customer: CustomerResponseData,
+    /// The assigned account, missing when the assignment failed
+    pub dedicated_account: Option<DedicatedVirtualAccountResponseData>,
+    /// Result of the customer identification that preceded the assignment
+    pub identification: Option<Value>,
+}
+
+/// Payload of the `invoice.*` events, raised for the invoices of a subscription.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct InvoiceData {
+    /// Domain of the invoice
+    pub domain: Option<Domain>,
+    /// Code of the invoice
+    pub invoice_code: String,
+    /// Amount of the invoice in the subunit of the currency
+    pub amount: u32,
+    /// Start of the billing period of the invoice
+    pub period_start: Option<String>,
+    /// End of the billing period of the invoice
+    pub period_end: Option<String>,
+    /// Status of the invoice e.g. `pending` or `success`
+    pub status: String,
+    /// If the invoice has been paid
+    pub paid: bool,
+    /// Time the invoice was paid
+    pub paid_at: Option<String>,
+    /// Description of the invoice
+    pub description: Option<String>,
+    /// Authorization the invoice is charged to
+    pub authorization: Option<Authorization>,
+    /// Summary of the subscription the invoice was raised for
+    pub subscription: Option<Value>,
+    /// The customer of the subscription
+    pub customer: Option<CustomerResponseData>,
+    /// Summary of the transaction that paid the invoice
+    pub transaction: Option<Value>,
+    /// Creation time of the invoice
+    pub created_at: Option<String>,
+}
+
+/// Payload of the `refund.*` events.
+///
+/// Paystack sends a summary of the refund in these events rather than the refund object
+/// returned by the Refunds endpoint, so it has its own model.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RefundEventData {
+    /// Status of the refund
+    pub status: RefundStatus,
+    /// Reference of the refunded transaction
+    pub transaction_reference: String,
+    /// Reference of the refund given by the processor
+    pub refund_reference: Option<String>,
+    /// Amount refunded in the subunit of the currency
+    pub amount: u64,
+    /// Currency of the refund
+    pub currency: Currency,
+    /// Processor of the refund e.g. `mastercard`
+    pub processor: Option<String>,
+    /// Name and email of the refunded customer
+    pub customer: Option<Value>,
+    /// Integration ID of the refund
+    pub integration: Option<u64>,
+    /// Domain of the refund
+    pub domain: Option<Domain>,
+}
+
+/// Represents the events Paystack sends to your webhook URL.
+///
+/// Typed payloads are boxed to keep the size of the enum small.
+/// `subscription.expiring_cards`, whose payload is a list of card summaries, carries its payload as raw JSON.
+/// Events the crate does not know about fall through to the `Unknown` variant
+/// instead of failing, so new Paystack events do not break existing handlers.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum WebhookEvent {
+    /// `charge.success`: A successful charge was made
+    ChargeSuccess(Box<TransactionStatusData>),
+    /// `charge.dispute.create`: A dispute was logged against your business
+    ChargeDisputeCreate(Box<DisputeResponseData>),
+    /// `charge.dispute.remind`: A logged dispute has not been resolved
+    ChargeDisputeRemind(Box<DisputeResponseData>),
+    /// `charge.dispute.resolve`: A dispute has been resolved
+    ChargeDisputeResolve(Box<DisputeResponseData>),
+    /// `customeridentification.success`: A customer ID validation was successful
+    CustomerIdentificationSuccess(Box<CustomerResponseData>),
+    /// `customeridentification.failed`: A customer ID validation has failed
+    CustomerIdentificationFailed(Box<CustomerResponseData>),
+    /// `dedicatedaccount.assign.success`: A DVA has been successfully created and assigned to a customer
+    DedicatedAccountAssignSuccess(Box<DedicatedAccountAssignmentData>),
+    /// `dedicatedaccount.assign.failed`: A DVA couldn't be created and assigned to a customer
+    DedicatedAccountAssignFailed(Box<DedicatedAccountAssignmentData>),
+    /// `invoice.create`: An invoice has been created for a subscription on your account
+    InvoiceCreate(Box<InvoiceData>),
+    /// `invoice.payment_failed`: A payment for an invoice failed
+    InvoicePaymentFailed(Box<InvoiceData>),
+    /// `invoice.update`: An invoice has been updated
+    InvoiceUpdate(Box<InvoiceData>),
+    /// `paymentrequest.pending`: A payment request has been sent to a customer
+    PaymentRequestPending(Box<PaymentRequestResponseData>),
+    /// `paymentrequest.success`: A payment request has been paid for
+    PaymentRequestSuccess(Box<PaymentRequestResponseData>),
+    /// `refund.failed`: Refund cannot be processed
+    RefundFailed(Box<RefundEventData>),
+    /// `refund.pending`: Refund initiated, waiting for response from the processor
+    RefundPending(Box<RefundEventData>),
+    /// `refund.processed`: Refund has successfully been processed by the processor
+    RefundProcessed(Box<RefundEventData>),
+    /// `refund.processing`: Refund has been received by the processor
+    RefundProcessing(Box<RefundEventData>),
+    /// `subscription.create`: A subscription has been created
+    SubscriptionCreate(Box<Subscription>),
+    /// `subscription.disable`: A subscription on your account has been disabled
+    SubscriptionDisable(Box<Subscription>),
+    /// `subscription.expiring_cards`: Contains information on all subscriptions with cards that are expiring that month
+    SubscriptionExpiringCards(Value),
+    /// `subscription.not_renew`: A subscription on your account's status has changed to non-renewing
+    SubscriptionNotRenew(Box<Subscription>),
+    /// `transfer.failed`: A transfer you attempted has failed
+    TransferFailed(Box<TransferResponseData>),
+    /// `transfer.success`: A successful transfer has been completed
+    TransferSuccess(Box<TransferResponseData>),
+    /// `transfer.reversed`: A transfer you attempted has been reversed
+    TransferReversed(Box<TransferResponseData>),
+    /// Any event not (yet) modelled by the crate
+    Unknown {
+        /// Name of the event
+        event: String,
+        /// Raw payload of the event
+        data: Value,
+    },
+}
+
+impl WebhookEvent {
+    /// Builds a typed event from the raw webhook envelope.
+    ///
+    /// # Arguments
+    /// * `payload` - The raw webhook envelope
+    ///
+    /// # Returns
+    /// A Result containing the typed event or the serde error raised while parsing the payload
+    pub fn from_payload(payload: WebhookPayload) -> Result<WebhookEvent, serde_json::Error> {
+        let WebhookPayload { event, data } = payload;
+        let event = match event.as_str() {
+            "charge.success" => WebhookEvent::ChargeSuccess(serde_json::from_value(data)?),
+            "charge.dispute.create" => {
+                WebhookEvent::ChargeDisputeCreate(serde_json::from_value(data)?)
+            }
+            "charge.dispute.remind" => {
+                WebhookEvent::ChargeDisputeRemind(serde_json::from_value(data)?)
+            }
+            "charge.dispute.resolve" => {
+                WebhookEvent::ChargeDisputeResolve(serde_json::from_value(data)?)
+            }
+            "customeridentification.success" => {
+                WebhookEvent::CustomerIdentificationSuccess(serde_json::from_value(data)?)
+            }
+            "customeridentification.failed" => {
+                WebhookEvent::CustomerIdentificationFailed(serde_json::from_value(data)?)
+            }
+            "dedicatedaccount.assign.success" => {
+                WebhookEvent::DedicatedAccountAssignSuccess(serde_json::from_value(data)?)
+            }
+            "dedicatedaccount.assign.failed" => {
+                WebhookEvent::DedicatedAccountAssignFailed(serde_json::from_value(data)?)
+            }
+            "invoice.create" => WebhookEvent::InvoiceCreate(serde_json::from_value(data)?),
+            "invoice.payment_failed" => {
+                WebhookEvent::InvoicePaymentFailed(serde_json::from_value(data)?)
+            }
+            "invoice.update" => WebhookEvent::InvoiceUpdate(serde_json::from_value(data)?),
+            "paymentrequest.pending" => {
+                WebhookEvent::PaymentRequestPending(serde_json::from_value(data)?)
+            }
+            "paymentrequest.success" => {
+                WebhookEvent::PaymentRequestSuccess(serde_json::from_value(data)?)
+            }
+            "refund.failed" => WebhookEvent::RefundFailed(serde_json::from_value(data)?),
+            "refund.pending" => WebhookEvent::RefundPending(serde_json::from_value(data)?),
+            "refund.processed" => WebhookEvent::RefundProcessed(serde_json::from_value(data)?),
+            "refund.processing" => WebhookEvent::RefundProcessing(serde_json::from_value(data)?),
+            "subscription.create" => {
+                WebhookEvent::SubscriptionCreate(serde_json::from_value(data)?)
+            }
+            "subscription.disable" => {
+                WebhookEvent::SubscriptionDisable(serde_json::from_value(data)?)
+            }
+            "subscription.expiring_cards" => WebhookEvent::SubscriptionExpiringCards(data),
+            "subscription.not_renew" => {
+                WebhookEvent::SubscriptionNotRenew(serde_json::from_value(data)?)
+            }
+            "transfer.failed" => WebhookEvent::TransferFailed(serde_json::from_value(data)?),
+            "transfer.success" => WebhookEvent::TransferSuccess(serde_json::from_value(data)?),
+            "transfer.reversed" => WebhookEvent::TransferReversed(serde_json::from_value(data)?),
+            _ => WebhookEvent::Unknown { event, data },
+        };
+
+        Ok(event)
+    }
+
+    /// Returns the Paystack name of the event e.g. `charge.success`
+    pub fn event_name(&self) -> &str {
+        match self {
+            WebhookEvent::ChargeSuccess(_) => "charge.success",
+            WebhookEvent::ChargeDisputeCreate(_) => "charge.dispute.create",
+            WebhookEvent::ChargeDisputeRemind(_) => "charge.dispute.remind",
+            WebhookEvent::ChargeDisputeResolve(_) => "charge.dispute.resolve",
+            WebhookEvent::CustomerIdentificationSuccess(_) => "customeridentification.success",
+            WebhookEvent::CustomerIdentificationFailed(_) => "customeridentification.failed",
+            WebhookEvent::DedicatedAccountAssignSuccess(_) => "dedicatedaccount.assign.success",
+            WebhookEvent::DedicatedAccountAssignFailed(_) => "dedicatedaccount.assign.failed",
+            WebhookEvent::InvoiceCreate(_) => "invoice.create",
+            WebhookEvent::InvoicePaymentFailed(_) => "invoice.payment_failed",
+            WebhookEvent::InvoiceUpdate(_) => "invoice.update",
+            WebhookEvent::PaymentRequestPending(_) => "paymentrequest.pending",
+            WebhookEvent::PaymentRequestSuccess(_) => "paymentrequest.success",
+            WebhookEvent::RefundFailed(_) => "refund.failed",
+            WebhookEvent::RefundPending(_) => "refund.pending",
+            WebhookEvent::RefundProcessed(_) => "refund.processed",
+            WebhookEvent::RefundProcessing(_) => "refund.processing",
+            WebhookEvent::SubscriptionCreate(_) => "subscription.create",
+            WebhookEvent::SubscriptionDisable(_) => "subscription.disable",
+            WebhookEvent::SubscriptionExpiringCards(_) => "subscription.expiring_cards",
+            WebhookEvent::SubscriptionNotRenew(_) => "subscription.not_renew",
+            WebhookEvent::TransferFailed(_) => "transfer.failed",
+            WebhookEvent::TransferSuccess(_) => "transfer.success",
+            WebhookEvent::TransferReversed(_) => "transfer.reversed",
+            WebhookEvent::Unknown { event, .. } => event,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Testing
+//! =======
+//! A local mock of the Paystack API, enabled with the `testing` feature.
+//!
+//! The mock keeps customers, plans, transactions, subaccounts and transaction splits in
+//! memory and answers with the same response envelopes as the Paystack API, so a payment
+//! flow can be run offline against a `PaystackClient` pointed at the mock.
+//!
+//! Only the routes of `customers`, `plans`, `transactions`, `subaccount` and
+//! `transaction_split` are mocked. Every other endpoint group of the client, e.g. charges,
+//! subscriptions, transfers, refunds, disputes, terminals, payment requests, products,
+//! payment pages, settlements, direct debit, storefronts, orders and verification,
+//! is answered with a `404` error by the mock.
+//!
+//! ```rust,no_run
+//! use paystack::testing::MockPaystackServer;
+//! use paystack::{Currency, TransactionRequestBuilder};
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = MockPaystackServer::start().await?;
+//! let client = server.client()?;
+//!
+//! let body = TransactionRequestBuilder::default()
+//!     .amount("10000".to_string())
+//!     .email("email@example.com".to_string())
+//!     .currency(Currency::NGN)
+//!     .build()?;
+//! let transaction = client.transactions.initialize_transaction(body).await?;
+//!
+//! // Pay the transaction as the customer would on the checkout page
+//! let reference = transaction.data.unwrap().reference;
+//! server.complete_transaction(&reference);
+//!
+//! let verified = client.transactions.verify_transaction(&reference).await?;
+//! assert_eq!(verified.data.unwrap().status, "success");
+//! # Ok(())
+//! # }
+//! ```
+
+mod routes;
+mod server;
+mod state;
+
+pub use server::MockPaystackServer;
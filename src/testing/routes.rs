@@ -0,0 +1,351 @@
+//! Routes
+//! ======
+//! This file contains the routes of the mock Paystack API.
+//!
+//! Every route answers with a Paystack response envelope: `status`, `message`, `data` and,
+//! for lists, the pagination `meta`. Errors are answered with the status code, `type` and
+//! `code` Paystack uses for them.
+
+use super::state::{MockError, MockResult, MockState};
+use axum::{
+    extract::{Path, Query, Request, State},
+    http::header::AUTHORIZATION,
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// State shared by the routes of the mock
+pub(crate) type SharedState = Arc<Mutex<MockState>>;
+
+type Params = Query<HashMap<String, String>>;
+
+/// Creates the router of the mock Paystack API
+pub(crate) fn router(state: SharedState) -> Router {
+    Router::new()
+        // Customers
+        .route("/customer", post(create_customer).get(list_customers))
+        .route("/customer/set_risk_action", post(set_risk_action))
+        .route(
+            "/customer/authorization/deactivate",
+            post(deactivate_authorization),
+        )
+        .route("/customer/{code}", get(fetch_customer).put(update_customer))
+        .route("/customer/{code}/identification", post(validate_customer))
+        // Plans
+        .route("/plan", post(create_plan).get(list_plans))
+        .route("/plan/{id_or_code}", get(fetch_plan).put(update_plan))
+        // Transactions
+        .route("/transaction", get(list_transactions))
+        .route("/transaction/initialize", post(initialize_transaction))
+        .route("/transaction/verify/{reference}", get(verify_transaction))
+        .route(
+            "/transaction/charge_authorization",
+            post(charge_authorization),
+        )
+        .route("/transaction/partial_debit", post(partial_debit))
+        .route("/transaction/totals", get(transaction_totals))
+        .route("/transaction/export", get(export_transactions))
+        .route(
+            "/transaction/timeline/{id_or_reference}",
+            get(transaction_timeline),
+        )
+        .route("/transaction/{id}", get(fetch_transaction))
+        // Subaccounts
+        .route("/subaccount", post(create_subaccount).get(list_subaccounts))
+        .route(
+            "/subaccount/{id_or_code}",
+            get(fetch_subaccount).put(update_subaccount),
+        )
+        // Transaction splits
+        .route("/split", post(create_split).get(list_splits))
+        .route("/split/{id}", get(fetch_split).put(update_split))
+        .route("/split/{id}/subaccount/add", post(add_split_subaccount))
+        .route(
+            "/split/{id}/subaccount/remove",
+            post(remove_split_subaccount),
+        )
+        .fallback(not_found)
+        .layer(middleware::from_fn(authenticate))
+        .with_state(state)
+}
+
+/// Rejects requests without a secret key, like the Paystack API does
+async fn authenticate(request: Request, next: Next) -> Response {
+    let authorized = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|key| key.starts_with("sk_"));
+
+    match authorized {
+        true => next.run(request).await,
+        false => error(MockError::unauthorized()),
+    }
+}
+
+async fn not_found() -> Response {
+    error(MockError::not_found(
+        "The requested route is not implemented by the mock",
+    ))
+}
+
+// Customers
+
+async fn create_customer(State(state): State<SharedState>, Json(body): Json<Value>) -> Response {
+    let result = state.lock().unwrap().create_customer(&body);
+    respond("Customer created", result)
+}
+
+async fn list_customers(State(state): State<SharedState>, Query(query): Params) -> Response {
+    let customers = state.lock().unwrap().list_customers();
+    paginate("Customers retrieved", customers, &query)
+}
+
+async fn fetch_customer(State(state): State<SharedState>, Path(code): Path<String>) -> Response {
+    let result = state.lock().unwrap().fetch_customer(&code);
+    respond("Customer retrieved", result)
+}
+
+async fn update_customer(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(body): Json<Value>,
+) -> Response {
+    let result = state.lock().unwrap().update_customer(&code, &body);
+    respond("Customer updated", result)
+}
+
+async fn validate_customer(State(state): State<SharedState>, Path(code): Path<String>) -> Response {
+    let result = state
+        .lock()
+        .unwrap()
+        .fetch_customer(&code)
+        .map(|_| Value::Null);
+    respond("Customer Identification in progress", result)
+}
+
+async fn set_risk_action(State(state): State<SharedState>, Json(body): Json<Value>) -> Response {
+    let result = state.lock().unwrap().set_risk_action(&body);
+    respond("Customer updated", result)
+}
+
+async fn deactivate_authorization(
+    State(state): State<SharedState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let result = state.lock().unwrap().deactivate_authorization(&body);
+    respond("Authorization has been deactivated", result)
+}
+
+// Plans
+
+async fn create_plan(State(state): State<SharedState>, Json(body): Json<Value>) -> Response {
+    let result = state.lock().unwrap().create_plan(&body);
+    respond("Plan created", result)
+}
+
+async fn list_plans(State(state): State<SharedState>, Query(query): Params) -> Response {
+    let plans = state.lock().unwrap().list_plans(&query);
+    paginate("Plans retrieved", plans, &query)
+}
+
+async fn fetch_plan(State(state): State<SharedState>, Path(id_or_code): Path<String>) -> Response {
+    let result = state.lock().unwrap().fetch_plan(&id_or_code);
+    respond("Plan retrieved", result)
+}
+
+async fn update_plan(
+    State(state): State<SharedState>,
+    Path(id_or_code): Path<String>,
+    Json(body): Json<Value>,
+) -> Response {
+    let result = state.lock().unwrap().update_plan(&id_or_code, &body);
+    respond("Plan updated. 0 subscription(s) affected", result)
+}
+
+// Transactions
+
+async fn initialize_transaction(
+    State(state): State<SharedState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let result = state.lock().unwrap().initialize_transaction(&body);
+    respond("Authorization URL created", result)
+}
+
+async fn verify_transaction(
+    State(state): State<SharedState>,
+    Path(reference): Path<String>,
+) -> Response {
+    let result = state.lock().unwrap().verify_transaction(&reference);
+    respond("Verification successful", result)
+}
+
+async fn list_transactions(State(state): State<SharedState>, Query(query): Params) -> Response {
+    let transactions = state.lock().unwrap().list_transactions(&query);
+    paginate("Transactions retrieved", transactions, &query)
+}
+
+async fn fetch_transaction(State(state): State<SharedState>, Path(id): Path<String>) -> Response {
+    let result = state.lock().unwrap().fetch_transaction(&id);
+    respond("Transaction retrieved", result)
+}
+
+async fn charge_authorization(
+    State(state): State<SharedState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let result = state.lock().unwrap().charge_authorization(&body);
+    respond("Charge attempted", result)
+}
+
+async fn partial_debit(State(state): State<SharedState>, Json(body): Json<Value>) -> Response {
+    let result = state.lock().unwrap().partial_debit(&body);
+    respond("Charge attempted", result)
+}
+
+async fn transaction_timeline(
+    State(state): State<SharedState>,
+    Path(id_or_reference): Path<String>,
+) -> Response {
+    let result = state.lock().unwrap().transaction_timeline(&id_or_reference);
+    respond("Timeline retrieved", result)
+}
+
+async fn export_transactions(State(state): State<SharedState>) -> Response {
+    let export = state.lock().unwrap().export_transactions();
+    respond("Export successful", Ok(export))
+}
+
+async fn transaction_totals(State(state): State<SharedState>) -> Response {
+    let totals = state.lock().unwrap().transaction_totals();
+    respond("Transaction totals", Ok(totals))
+}
+
+// Subaccounts
+
+async fn create_subaccount(State(state): State<SharedState>, Json(body): Json<Value>) -> Response {
+    let result = state.lock().unwrap().create_subaccount(&body);
+    respond("Subaccount created", result)
+}
+
+async fn list_subaccounts(State(state): State<SharedState>, Query(query): Params) -> Response {
+    let subaccounts = state.lock().unwrap().list_subaccounts();
+    paginate("Subaccounts retrieved", subaccounts, &query)
+}
+
+async fn fetch_subaccount(
+    State(state): State<SharedState>,
+    Path(id_or_code): Path<String>,
+) -> Response {
+    let result = state.lock().unwrap().fetch_subaccount(&id_or_code);
+    respond("Subaccount retrieved", result)
+}
+
+async fn update_subaccount(
+    State(state): State<SharedState>,
+    Path(id_or_code): Path<String>,
+    Json(body): Json<Value>,
+) -> Response {
+    let result = state.lock().unwrap().update_subaccount(&id_or_code, &body);
+    respond("Subaccount updated", result)
+}
+
+// Transaction splits
+
+async fn create_split(State(state): State<SharedState>, Json(body): Json<Value>) -> Response {
+    let result = state.lock().unwrap().create_split(&body);
+    respond("Split created", result)
+}
+
+async fn list_splits(State(state): State<SharedState>, Query(query): Params) -> Response {
+    let splits = state.lock().unwrap().list_splits(&query);
+    paginate("Split retrieved", splits, &query)
+}
+
+async fn fetch_split(State(state): State<SharedState>, Path(id): Path<String>) -> Response {
+    let result = state.lock().unwrap().fetch_split(&id);
+    respond("Split retrieved", result)
+}
+
+async fn update_split(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    Json(body): Json<Value>,
+) -> Response {
+    let result = state.lock().unwrap().update_split(&id, &body);
+    respond("Split group updated", result)
+}
+
+async fn add_split_subaccount(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    Json(body): Json<Value>,
+) -> Response {
+    let result = state.lock().unwrap().add_split_subaccount(&id, &body);
+    respond("Subaccount added", result)
+}
+
+async fn remove_split_subaccount(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    Json(body): Json<Value>,
+) -> Response {
+    let result = state.lock().unwrap().remove_split_subaccount(&id, &body);
+    respond("Subaccount removed", result)
+}
+
+// Responses
+
+/// Wraps the result of a route in a Paystack response envelope
+fn respond(message: &str, result: MockResult) -> Response {
+    match result {
+        Ok(Value::Null) => Json(json!({ "status": true, "message": message })).into_response(),
+        Ok(data) => {
+            Json(json!({ "status": true, "message": message, "data": data })).into_response()
+        }
+        Err(e) => error(e),
+    }
+}
+
+/// Wraps a page of records in a Paystack response envelope with the pagination meta
+fn paginate(message: &str, records: Vec<Value>, query: &HashMap<String, String>) -> Response {
+    let param = |key: &str, default: usize| {
+        query
+            .get(key)
+            .and_then(|value| value.parse().ok())
+            .filter(|value| *value > 0)
+            .unwrap_or(default)
+    };
+    let per_page = param("perPage", 50);
+    let page = param("page", 1);
+    let total = records.len();
+    let skipped = (page - 1) * per_page;
+    let data: Vec<Value> = records.into_iter().skip(skipped).take(per_page).collect();
+
+    Json(json!({
+        "status": true,
+        "message": message,
+        "data": data,
+        "meta": {
+            "total": total,
+            "skipped": skipped,
+            "perPage": per_page,
+            "page": page,
+            "pageCount": total.div_ceil(per_page),
+        },
+    }))
+    .into_response()
+}
+
+fn error(error: MockError) -> Response {
+    (error.status, Json(error.to_json())).into_response()
+}
@@ -0,0 +1,103 @@
+//! Server
+//! ======
+//! This file contains the mock Paystack server and the controls tests use to drive it.
+
+use super::routes::{router, SharedState};
+use crate::{PaystackAPIError, PaystackClient, ReqwestClient};
+use std::{io, net::SocketAddr};
+use tokio::{net::TcpListener, task::JoinHandle};
+
+/// A local HTTP server mocking the Paystack API, with in-memory customers, plans,
+/// transactions, subaccounts and transaction splits.
+///
+/// The server listens on a random local port and is stopped when dropped.
+#[derive(Debug)]
+pub struct MockPaystackServer {
+    /// Address the server listens on
+    address: SocketAddr,
+    /// Records of the mock, shared with the running server
+    state: SharedState,
+    /// Task running the server
+    handle: JoinHandle<()>,
+}
+
+impl MockPaystackServer {
+    /// Starts a new mock server on a random local port.
+    ///
+    /// # Returns
+    /// The running server, or an error if no local port could be bound
+    pub async fn start() -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let state = SharedState::default();
+
+        let app = router(state.clone());
+        let handle = tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, app).await {
+                log::error!("Mock Paystack server stopped: {e}");
+            }
+        });
+
+        Ok(MockPaystackServer {
+            address,
+            state,
+            handle,
+        })
+    }
+
+    /// The base URL of the server, to use in place of `https://api.paystack.co`
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Creates a PaystackClient that sends its requests to this server.
+    ///
+    /// # Returns
+    /// A new PaystackClient instance using a test secret key
+    pub fn client(&self) -> Result<PaystackClient<ReqwestClient>, PaystackAPIError> {
+        PaystackClient::builder("sk_test_mock".to_string())
+            .base_url(self.base_url())
+            .build()
+    }
+
+    /// Completes the payment of an initialized transaction, as if the customer paid on the checkout page.
+    /// The transaction is marked as successful and gets a reusable card authorization
+    /// that can be charged again with `charge_authorization`.
+    ///
+    /// # Arguments
+    /// * `reference` - The reference of the transaction
+    ///
+    /// # Returns
+    /// `true` if a pending transaction with the reference was found
+    pub fn complete_transaction(&self, reference: &str) -> bool {
+        self.state.lock().unwrap().complete_transaction(reference)
+    }
+
+    /// Sets the funds available on a card authorization. Authorizations have unlimited funds
+    /// until this is called, after which charges above the funds are declined and
+    /// `partial_debit` takes what is left.
+    ///
+    /// # Arguments
+    /// * `authorization_code` - The code of the authorization
+    /// * `amount` - The funds available, in the subunit of the currency
+    ///
+    /// # Returns
+    /// `true` if a transaction was paid with the authorization
+    pub fn set_available_funds(&self, authorization_code: &str, amount: u64) -> bool {
+        self.state
+            .lock()
+            .unwrap()
+            .set_available_funds(authorization_code, amount)
+    }
+
+    /// Removes all the records of the server
+    pub fn reset(&self) {
+        *self.state.lock().unwrap() = Default::default();
+    }
+}
+
+impl Drop for MockPaystackServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
@@ -0,0 +1,980 @@
+//! State
+//! =====
+//! This file contains the in-memory records of the mock Paystack API.
+//!
+//! Records are kept in the JSON shape Paystack returns them in, so the responses of the
+//! mock go through the same deserialization as the responses of the real API.
+
+use axum::http::StatusCode;
+use serde_json::{json, Map, Value};
+use std::{
+    collections::hash_map::RandomState,
+    collections::HashMap,
+    hash::{BuildHasher, Hasher},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Integration ID of the records created by the mock
+const INTEGRATION: u64 = 100032;
+
+/// Error response of the mock, rendered like a Paystack error response
+#[derive(Debug, Clone)]
+pub(crate) struct MockError {
+    pub status: StatusCode,
+    pub message: String,
+    pub error_type: &'static str,
+    pub code: &'static str,
+    pub next_step: Option<&'static str>,
+    pub errors: Option<Value>,
+}
+
+impl MockError {
+    pub fn not_found(message: impl Into<String>) -> Self {
+        MockError {
+            status: StatusCode::NOT_FOUND,
+            message: message.into(),
+            error_type: "validation_error",
+            code: "not_found",
+            next_step: None,
+            errors: None,
+        }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        MockError {
+            status: StatusCode::BAD_REQUEST,
+            message: message.into(),
+            error_type: "validation_error",
+            code: "invalid_params",
+            next_step: None,
+            errors: None,
+        }
+    }
+
+    pub fn unauthorized() -> Self {
+        MockError {
+            status: StatusCode::UNAUTHORIZED,
+            message: "Invalid key".to_string(),
+            error_type: "validation_error",
+            code: "invalid_Key",
+            next_step: Some(
+                "Ensure that you provide the correct authorization key for the request",
+            ),
+            errors: None,
+        }
+    }
+
+    fn missing(fields: &[&str]) -> Self {
+        let errors: Map<String, Value> = fields
+            .iter()
+            .map(|field| {
+                let error =
+                    json!([{"rule": "required", "message": format!("{field} is required")}]);
+                (field.to_string(), error)
+            })
+            .collect();
+        MockError {
+            status: StatusCode::BAD_REQUEST,
+            message: "Invalid params".to_string(),
+            error_type: "validation_error",
+            code: "missing_params",
+            next_step: Some("Provide all required params"),
+            errors: Some(Value::Object(errors)),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut body = json!({
+            "status": false,
+            "message": self.message,
+            "type": self.error_type,
+            "code": self.code,
+        });
+        if let Some(next_step) = self.next_step {
+            body["meta"] = json!({ "nextStep": next_step });
+        }
+        if let Some(errors) = &self.errors {
+            body["errors"] = errors.clone();
+        }
+        body
+    }
+}
+
+pub(crate) type MockResult = Result<Value, MockError>;
+
+/// In-memory records of the mock Paystack API
+#[derive(Debug, Default)]
+pub(crate) struct MockState {
+    next_id: u64,
+    customers: Vec<Value>,
+    plans: Vec<Value>,
+    transactions: Vec<Value>,
+    subaccounts: Vec<Value>,
+    splits: Vec<Value>,
+    /// Funds available on card authorizations, authorizations without an entry have unlimited funds
+    funds: HashMap<String, u64>,
+}
+
+impl MockState {
+    fn id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    // Customers
+
+    pub fn create_customer(&mut self, body: &Value) -> MockResult {
+        required(body, &["email"])?;
+        let email = text(body, "email").unwrap_or_default();
+        if let Some(customer) = self.find_customer(&email) {
+            return Ok(self.render_customer(customer));
+        }
+
+        let id = self.id();
+        let now = timestamp();
+        let customer = json!({
+            "id": id,
+            "integration": INTEGRATION,
+            "domain": "test",
+            "identified": false,
+            "first_name": body.get("first_name"),
+            "last_name": body.get("last_name"),
+            "email": email,
+            "customer_code": random_code("CUS_", 15),
+            "phone": body.get("phone"),
+            "metadata": null,
+            "risk_action": "default",
+            "international_format_phone": null,
+            "createdAt": now,
+            "updatedAt": now,
+        });
+        self.customers.push(customer);
+        Ok(self.render_customer(self.customers.len() - 1))
+    }
+
+    pub fn list_customers(&self) -> Vec<Value> {
+        (0..self.customers.len())
+            .rev()
+            .map(|index| self.render_customer(index))
+            .collect()
+    }
+
+    pub fn fetch_customer(&self, email_or_code: &str) -> MockResult {
+        let index = self
+            .find_customer(email_or_code)
+            .ok_or_else(|| MockError::not_found("Customer not found"))?;
+        Ok(self.render_customer(index))
+    }
+
+    pub fn update_customer(&mut self, code: &str, body: &Value) -> MockResult {
+        let index = self
+            .find_customer(code)
+            .ok_or_else(|| MockError::not_found("Customer not found"))?;
+        merge(
+            &mut self.customers[index],
+            body,
+            &["first_name", "last_name", "phone", "metadata"],
+        );
+        Ok(self.render_customer(index))
+    }
+
+    pub fn set_risk_action(&mut self, body: &Value) -> MockResult {
+        required(body, &["customer"])?;
+        let code = text(body, "customer").unwrap_or_default();
+        let index = self
+            .find_customer(&code)
+            .ok_or_else(|| MockError::not_found("Customer not found"))?;
+        let risk_action = field(body, "risk_action", "default");
+        self.customers[index]["risk_action"] = risk_action;
+        self.customers[index]["updatedAt"] = timestamp().into();
+        Ok(self.render_customer(index))
+    }
+
+    pub fn deactivate_authorization(&mut self, body: &Value) -> MockResult {
+        required(body, &["authorization_code"])?;
+        let code = text(body, "authorization_code");
+        let authorizations = self
+            .transactions
+            .iter_mut()
+            .map(|transaction| &mut transaction["authorization"])
+            .filter(|authorization| text(authorization, "authorization_code") == code);
+
+        let mut found = false;
+        for authorization in authorizations {
+            authorization["reusable"] = false.into();
+            found = true;
+        }
+        match found {
+            true => Ok(Value::Null),
+            false => Err(MockError::not_found("Authorization code not found")),
+        }
+    }
+
+    fn find_customer(&self, key: &str) -> Option<usize> {
+        self.customers.iter().position(|customer| {
+            text(customer, "customer_code").as_deref() == Some(key)
+                || text(customer, "email").is_some_and(|email| email.eq_ignore_ascii_case(key))
+                || has_id(customer, key)
+        })
+    }
+
+    fn render_customer(&self, index: usize) -> Value {
+        let mut customer = self.customers[index].clone();
+        let authorizations: Vec<Value> = self
+            .transactions
+            .iter()
+            .filter(|t| t["customer_id"] == customer["id"] && t["status"] == "success")
+            .map(|t| t["authorization"].clone())
+            .filter(|authorization| authorization["reusable"] == true)
+            .collect();
+
+        customer["transactions"] = json!([]);
+        customer["subscriptions"] = json!([]);
+        customer["authorizations"] = Value::Array(authorizations);
+        customer
+    }
+
+    // Plans
+
+    pub fn create_plan(&mut self, body: &Value) -> MockResult {
+        required(body, &["name", "amount", "interval"])?;
+        let id = self.id();
+        let now = timestamp();
+        let plan = json!({
+            "id": id,
+            "name": body["name"],
+            "amount": amount(body, "amount")?,
+            "interval": body["interval"],
+            "integration": INTEGRATION,
+            "domain": "test",
+            "plan_code": random_code("PLN_", 15),
+            "description": body.get("description"),
+            "send_invoices": field(body, "send_invoices", true),
+            "send_sms": field(body, "send_sms", true),
+            "hosted_page": false,
+            "hosted_page_url": null,
+            "hosted_page_summary": null,
+            "currency": field(body, "currency", "NGN"),
+            "invoice_limit": field(body, "invoice_limit", 0),
+            "createdAt": now,
+            "updatedAt": now,
+        });
+        self.plans.push(plan.clone());
+        Ok(plan)
+    }
+
+    pub fn list_plans(&self, query: &HashMap<String, String>) -> Vec<Value> {
+        self.plans
+            .iter()
+            .rev()
+            .filter(|plan| matches(plan, "interval", query.get("interval")))
+            .filter(|plan| matches(plan, "amount", query.get("amount")))
+            .map(|plan| {
+                let mut plan = plan.clone();
+                plan["subscriptions"] = json!([]);
+                plan
+            })
+            .collect()
+    }
+
+    pub fn fetch_plan(&self, id_or_code: &str) -> MockResult {
+        let index = self.find_plan(id_or_code)?;
+        let mut plan = self.plans[index].clone();
+        plan["subscriptions"] = json!([]);
+        Ok(plan)
+    }
+
+    pub fn update_plan(&mut self, id_or_code: &str, body: &Value) -> MockResult {
+        let index = self.find_plan(id_or_code)?;
+        if body.get("amount").is_some_and(|amount| !amount.is_null()) {
+            self.plans[index]["amount"] = amount(body, "amount")?.into();
+        }
+        merge(
+            &mut self.plans[index],
+            body,
+            &[
+                "name",
+                "interval",
+                "description",
+                "send_invoices",
+                "send_sms",
+                "currency",
+                "invoice_limit",
+            ],
+        );
+        Ok(Value::Null)
+    }
+
+    fn find_plan(&self, id_or_code: &str) -> Result<usize, MockError> {
+        self.plans
+            .iter()
+            .position(|plan| has_id_or_code(plan, "plan_code", id_or_code))
+            .ok_or_else(|| MockError::not_found("Plan not found"))
+    }
+
+    // Transactions
+
+    pub fn initialize_transaction(&mut self, body: &Value) -> MockResult {
+        required(body, &["email", "amount"])?;
+        let transaction = self.new_transaction(body)?;
+        let response = json!({
+            "authorization_url": format!("https://checkout.paystack.com/{}", transaction["access_code"].as_str().unwrap_or_default()),
+            "access_code": transaction["access_code"],
+            "reference": transaction["reference"],
+        });
+        self.transactions.push(transaction);
+        Ok(response)
+    }
+
+    pub fn charge_authorization(&mut self, body: &Value) -> MockResult {
+        required(body, &["email", "amount", "authorization_code"])?;
+        let authorization = self.find_authorization(body)?;
+        let code = text(&authorization, "authorization_code").unwrap_or_default();
+
+        let mut transaction = self.new_transaction(body)?;
+        let requested = transaction["amount"].as_u64().unwrap_or_default();
+        match self.debit(&code, requested, requested) {
+            Some(_) => complete(&mut transaction, authorization),
+            None => decline(&mut transaction, authorization),
+        }
+        self.transactions.push(transaction);
+
+        Ok(self.render_charge(self.transactions.len() - 1))
+    }
+
+    /// Charges as much of the amount as the authorization has funds for,
+    /// and no less than `at_least` if it is given
+    pub fn partial_debit(&mut self, body: &Value) -> MockResult {
+        required(body, &["authorization_code", "currency", "amount", "email"])?;
+        if !matches!(text(body, "currency").as_deref(), Some("NGN" | "GHS")) {
+            return Err(MockError::invalid(
+                "Currency not supported for partial debit",
+            ));
+        }
+        let requested = amount(body, "amount")?;
+        let at_least = match body.get("at_least").filter(|value| !value.is_null()) {
+            Some(_) => amount(body, "at_least")?,
+            None => 1,
+        };
+        if at_least > requested {
+            return Err(MockError::invalid("at_least cannot be more than amount"));
+        }
+        let authorization = self.find_authorization(body)?;
+        let code = text(&authorization, "authorization_code").unwrap_or_default();
+
+        let mut transaction = self.new_transaction(body)?;
+        match self.debit(&code, requested, at_least) {
+            Some(debited) => {
+                transaction["amount"] = debited.into();
+                complete(&mut transaction, authorization);
+            }
+            None => decline(&mut transaction, authorization),
+        }
+        self.transactions.push(transaction);
+
+        let mut response = self.render_charge(self.transactions.len() - 1);
+        response["requested_amount"] = requested.into();
+        Ok(response)
+    }
+
+    /// Sets the funds available on a card authorization
+    pub fn set_available_funds(&mut self, authorization_code: &str, amount: u64) -> bool {
+        let known = self.transactions.iter().any(|t| {
+            text(&t["authorization"], "authorization_code").as_deref() == Some(authorization_code)
+        });
+        if known {
+            self.funds.insert(authorization_code.to_string(), amount);
+        }
+        known
+    }
+
+    pub fn verify_transaction(&self, reference: &str) -> MockResult {
+        let index = self
+            .transactions
+            .iter()
+            .position(|t| text(t, "reference").as_deref() == Some(reference))
+            .ok_or_else(|| MockError::not_found("Transaction reference not found"))?;
+        Ok(self.render_transaction(index))
+    }
+
+    pub fn list_transactions(&self, query: &HashMap<String, String>) -> Vec<Value> {
+        (0..self.transactions.len())
+            .rev()
+            .filter(|&i| matches(&self.transactions[i], "status", query.get("status")))
+            .filter(|&i| matches(&self.transactions[i], "customer_id", query.get("customer")))
+            .map(|i| self.render_transaction(i))
+            .collect()
+    }
+
+    pub fn fetch_transaction(&self, id: &str) -> MockResult {
+        let index = self
+            .transactions
+            .iter()
+            .position(|t| has_id(t, id))
+            .ok_or_else(|| MockError::not_found("Transaction not found"))?;
+        Ok(self.render_transaction(index))
+    }
+
+    pub fn transaction_timeline(&self, id_or_reference: &str) -> MockResult {
+        let transaction = self
+            .transactions
+            .iter()
+            .find(|t| has_id(t, id_or_reference) || t["reference"] == id_or_reference)
+            .ok_or_else(|| MockError::not_found("Transaction not found"))?;
+        let success = transaction["status"] == "success";
+
+        let mut history = vec![json!({
+            "type": "action",
+            "message": "Attempted to pay with card",
+            "time": 1,
+        })];
+        if success {
+            history.push(json!({
+                "type": "success",
+                "message": "Successfully paid with card",
+                "time": 5,
+            }));
+        }
+
+        Ok(json!({
+            "time_spent": if success { 5 } else { 1 },
+            "attempts": 1,
+            "authentication": null,
+            "errors": 0,
+            "success": success,
+            "mobile": false,
+            "input": null,
+            "channel": transaction["channel"],
+            "history": history,
+        }))
+    }
+
+    pub fn export_transactions(&self) -> Value {
+        json!({
+            "path": format!(
+                "https://files.paystack.co/exports/{INTEGRATION}/transactions_{}.csv",
+                random_code("", 10)
+            ),
+        })
+    }
+
+    pub fn transaction_totals(&self) -> Value {
+        let successful: Vec<&Value> = self
+            .transactions
+            .iter()
+            .filter(|t| t["status"] == "success")
+            .collect();
+
+        let mut customers: Vec<&Value> = successful.iter().map(|t| &t["customer_id"]).collect();
+        customers.sort_by_key(|id| id.to_string());
+        customers.dedup();
+
+        let mut volume_by_currency: Vec<(String, u64)> = vec![];
+        for transaction in &successful {
+            let currency = text(transaction, "currency").unwrap_or_default();
+            let amount = transaction["amount"].as_u64().unwrap_or_default();
+            match volume_by_currency.iter_mut().find(|(c, _)| *c == currency) {
+                Some((_, total)) => *total += amount,
+                None => volume_by_currency.push((currency, amount)),
+            }
+        }
+
+        json!({
+            "total_transactions": successful.len(),
+            "unique_customers": customers.len(),
+            "total_volume": volume_by_currency.iter().map(|(_, amount)| amount).sum::<u64>(),
+            "total_volume_by_currency": volume_by_currency
+                .iter()
+                .map(|(currency, amount)| json!({"currency": currency, "amount": amount}))
+                .collect::<Vec<_>>(),
+            "pending_transfers": 0,
+            "pending_transfers_by_currency": [],
+        })
+    }
+
+    /// Marks a transaction as paid by card, as if the customer completed the checkout
+    pub fn complete_transaction(&mut self, reference: &str) -> bool {
+        let authorization = json!({
+            "authorization_code": random_code("AUTH_", 10),
+            "bin": "408408",
+            "last4": "4081",
+            "exp_month": "12",
+            "exp_year": "2030",
+            "channel": "card",
+            "card_type": "visa ",
+            "bank": "TEST BANK",
+            "country_code": "NG",
+            "brand": "visa",
+            "reusable": true,
+            "signature": random_code("SIG_", 20),
+            "account_name": null,
+        });
+        let transaction = self.transactions.iter_mut().find(|t| {
+            text(t, "reference").as_deref() == Some(reference) && t["status"] != "success"
+        });
+
+        match transaction {
+            Some(transaction) => {
+                complete(transaction, authorization);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn new_transaction(&mut self, body: &Value) -> MockResult {
+        let amount = amount(body, "amount")?;
+        let reference = text(body, "reference").unwrap_or_else(|| random_code("", 10));
+        if self
+            .transactions
+            .iter()
+            .any(|t| text(t, "reference").as_deref() == Some(reference.as_str()))
+        {
+            return Err(MockError::invalid("Duplicate Transaction Reference"));
+        }
+
+        let customer = self.create_customer(&json!({ "email": body["email"] }))?;
+        let id = self.id();
+        let now = timestamp();
+        Ok(json!({
+            "id": id,
+            "domain": "test",
+            "status": "abandoned",
+            "reference": reference,
+            "amount": amount,
+            "message": null,
+            "gateway_response": "The transaction was not completed",
+            "paid_at": null,
+            "created_at": now,
+            "createdAt": now,
+            "channel": "card",
+            "currency": field(body, "currency", "NGN"),
+            "ip_address": null,
+            "metadata": body.get("metadata").filter(|m| m.is_string()),
+            "fees": null,
+            "authorization": {},
+            "customer_id": customer["id"],
+            "access_code": random_code("", 15),
+        }))
+    }
+
+    fn customer_email(&self, id: &Value) -> Option<String> {
+        self.customers
+            .iter()
+            .find(|customer| customer["id"] == *id)
+            .and_then(|customer| text(customer, "email"))
+    }
+
+    /// Finds the reusable authorization of the customer to charge
+    fn find_authorization(&self, body: &Value) -> MockResult {
+        let code = text(body, "authorization_code");
+        let email = text(body, "email").unwrap_or_default();
+        self.transactions
+            .iter()
+            .find(|t| {
+                text(&t["authorization"], "authorization_code") == code
+                    && self.customer_email(&t["customer_id"]).as_deref() == Some(email.as_str())
+            })
+            .map(|t| t["authorization"].clone())
+            .filter(|authorization| authorization["reusable"] == true)
+            .ok_or_else(|| MockError::invalid("Invalid authorization code"))
+    }
+
+    /// Takes up to `amount` from the funds of an authorization, but no less than `at_least`
+    ///
+    /// # Returns
+    /// The amount taken, or `None` if the authorization does not have `at_least` available
+    fn debit(&mut self, authorization_code: &str, amount: u64, at_least: u64) -> Option<u64> {
+        let Some(available) = self.funds.get_mut(authorization_code) else {
+            return Some(amount);
+        };
+        let debited = amount.min(*available);
+        if debited < at_least {
+            return None;
+        }
+        *available -= debited;
+        Some(debited)
+    }
+
+    fn render_charge(&self, index: usize) -> Value {
+        let mut response = self.render_transaction(index);
+        response["transaction_date"] = response["created_at"].clone();
+        response["plan"] = Value::Null;
+        response
+    }
+
+    fn render_transaction(&self, index: usize) -> Value {
+        let mut transaction = self.transactions[index].clone();
+        let customer = transaction
+            .as_object_mut()
+            .and_then(|t| {
+                t.remove("access_code");
+                t.remove("customer_id")
+            })
+            .and_then(|id| self.customers.iter().find(|c| c["id"] == id).cloned());
+        transaction["customer"] = customer.unwrap_or_default();
+        transaction
+    }
+
+    // Subaccounts
+
+    pub fn create_subaccount(&mut self, body: &Value) -> MockResult {
+        required(
+            body,
+            &[
+                "business_name",
+                "settlement_bank",
+                "account_number",
+                "percentage_charge",
+            ],
+        )?;
+        let id = self.id();
+        let now = timestamp();
+        let subaccount = json!({
+            "id": id,
+            "integration": INTEGRATION,
+            "domain": "test",
+            "subaccount_code": random_code("ACCT_", 15),
+            "business_name": body["business_name"],
+            "description": body.get("description"),
+            "primary_contact_name": body.get("primary_contact_name"),
+            "primary_contact_email": body.get("primary_contact_email"),
+            "primary_contact_phone": body.get("primary_contact_phone"),
+            "metadata": body.get("metadata").filter(|m| m.is_string()),
+            "percentage_charge": body["percentage_charge"],
+            "is_verified": false,
+            "settlement_bank": body["settlement_bank"],
+            "bank_id": null,
+            "account_number": body["account_number"],
+            "currency": "NGN",
+            "active": true,
+            "settlement_schedule": "AUTO",
+            "createdAt": now,
+            "updatedAt": now,
+            "product": "collection",
+            "managed_by_integration": INTEGRATION,
+        });
+        self.subaccounts.push(subaccount.clone());
+        Ok(subaccount)
+    }
+
+    pub fn list_subaccounts(&self) -> Vec<Value> {
+        self.subaccounts.iter().rev().cloned().collect()
+    }
+
+    pub fn fetch_subaccount(&self, id_or_code: &str) -> MockResult {
+        let index = self.find_subaccount(id_or_code)?;
+        Ok(self.subaccounts[index].clone())
+    }
+
+    pub fn update_subaccount(&mut self, id_or_code: &str, body: &Value) -> MockResult {
+        let index = self.find_subaccount(id_or_code)?;
+        merge(
+            &mut self.subaccounts[index],
+            body,
+            &[
+                "business_name",
+                "settlement_bank",
+                "account_number",
+                "active",
+                "percentage_charge",
+                "description",
+                "primary_contact_email",
+                "primary_contact_name",
+                "primary_contact_phone",
+                "settlement_schedule",
+                "metadata",
+            ],
+        );
+        Ok(self.subaccounts[index].clone())
+    }
+
+    fn find_subaccount(&self, id_or_code: &str) -> Result<usize, MockError> {
+        self.subaccounts
+            .iter()
+            .position(|subaccount| has_id_or_code(subaccount, "subaccount_code", id_or_code))
+            .ok_or_else(|| MockError::not_found("Subaccount not found"))
+    }
+
+    // Transaction splits
+
+    pub fn create_split(&mut self, body: &Value) -> MockResult {
+        required(body, &["name", "type", "currency", "subaccounts"])?;
+        let mut shares = vec![];
+        for subaccount in body["subaccounts"].as_array().into_iter().flatten() {
+            shares.push(self.split_share(subaccount)?);
+        }
+        let bearer_subaccount = self.bearer_subaccount(body)?;
+
+        let id = self.id();
+        let now = timestamp();
+        let split = json!({
+            "id": id,
+            "name": body["name"],
+            "type": body["type"],
+            "currency": body["currency"],
+            "integration": INTEGRATION,
+            "domain": "test",
+            "split_code": random_code("SPL_", 10),
+            "active": true,
+            "bearer_type": field(body, "bearer_type", "account"),
+            "bearer_subaccount": bearer_subaccount,
+            "createdAt": now,
+            "updatedAt": now,
+            "is_dynamic": false,
+            "subaccounts": shares,
+        });
+        self.splits.push(split);
+        Ok(self.render_split(self.splits.len() - 1))
+    }
+
+    pub fn list_splits(&self, query: &HashMap<String, String>) -> Vec<Value> {
+        let filter = |key: &str| query.get(key).filter(|value| !value.is_empty());
+        (0..self.splits.len())
+            .rev()
+            .filter(|&i| matches(&self.splits[i], "name", filter("name")))
+            .filter(|&i| matches(&self.splits[i], "active", filter("active")))
+            .map(|i| self.render_split(i))
+            .collect()
+    }
+
+    pub fn fetch_split(&self, id: &str) -> MockResult {
+        let index = self.find_split(id)?;
+        Ok(self.render_split(index))
+    }
+
+    pub fn update_split(&mut self, id: &str, body: &Value) -> MockResult {
+        required(body, &["name"])?;
+        let index = self.find_split(id)?;
+        if body.get("bearer_subaccount").is_some_and(|b| !b.is_null()) {
+            self.splits[index]["bearer_subaccount"] = self.bearer_subaccount(body)?.into();
+        }
+        merge(
+            &mut self.splits[index],
+            body,
+            &["name", "active", "bearer_type"],
+        );
+        Ok(self.render_split(index))
+    }
+
+    pub fn add_split_subaccount(&mut self, id: &str, body: &Value) -> MockResult {
+        let index = self.find_split(id)?;
+        let share = self.split_share(body)?;
+        let subaccounts = self.splits[index]["subaccounts"]
+            .as_array_mut()
+            .expect("split subaccounts are an array");
+        match subaccounts
+            .iter_mut()
+            .find(|s| s["subaccount"] == share["subaccount"])
+        {
+            Some(existing) => *existing = share,
+            None => subaccounts.push(share),
+        }
+        Ok(self.render_split(index))
+    }
+
+    pub fn remove_split_subaccount(&mut self, id: &str, body: &Value) -> MockResult {
+        required(body, &["subaccount"])?;
+        let index = self.find_split(id)?;
+        let subaccounts = self.splits[index]["subaccounts"]
+            .as_array_mut()
+            .expect("split subaccounts are an array");
+        let count = subaccounts.len();
+        subaccounts.retain(|s| s["subaccount"] != body["subaccount"]);
+        match subaccounts.len() < count {
+            true => Ok(Value::Null),
+            false => Err(MockError::not_found("Subaccount not found in split")),
+        }
+    }
+
+    fn find_split(&self, id: &str) -> Result<usize, MockError> {
+        self.splits
+            .iter()
+            .position(|split| has_id_or_code(split, "split_code", id))
+            .ok_or_else(|| MockError::not_found("Split not found"))
+    }
+
+    /// Validates a `{subaccount, share}` entry of a split
+    fn split_share(&self, body: &Value) -> MockResult {
+        required(body, &["subaccount", "share"])?;
+        let code = text(body, "subaccount").unwrap_or_default();
+        self.find_subaccount(&code)
+            .map_err(|_| MockError::invalid(format!("Subaccount {code} not found")))?;
+        let share = body["share"].as_f64().unwrap_or_default().round() as u64;
+        Ok(json!({ "subaccount": code, "share": share }))
+    }
+
+    /// Resolves the bearer subaccount code of a split to the ID of the subaccount
+    fn bearer_subaccount(&self, body: &Value) -> Result<u64, MockError> {
+        let bearer = &body["bearer_subaccount"];
+        let code = text(bearer, "subaccount").or_else(|| bearer.as_str().map(str::to_string));
+        let id = code
+            .and_then(|code| self.find_subaccount(&code).ok())
+            .and_then(|index| self.subaccounts[index]["id"].as_u64());
+
+        match id {
+            Some(id) => Ok(id),
+            None if body["bearer_type"] == "subaccount" => {
+                Err(MockError::invalid("Bearer subaccount not found"))
+            }
+            None => Ok(0),
+        }
+    }
+
+    fn render_split(&self, index: usize) -> Value {
+        let mut split = self.splits[index].clone();
+        let subaccounts: Vec<Value> = split["subaccounts"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|share| {
+                let code = share["subaccount"].as_str()?;
+                let index = self.find_subaccount(code).ok()?;
+                Some(json!({ "subaccount": self.subaccounts[index], "share": share["share"] }))
+            })
+            .collect();
+
+        split["total_subaccounts"] = subaccounts.len().into();
+        split["subaccounts"] = Value::Array(subaccounts);
+        split
+    }
+}
+
+/// Marks a transaction as paid with the given authorization
+fn complete(transaction: &mut Value, authorization: Value) {
+    let amount = transaction["amount"].as_u64().unwrap_or_default();
+    transaction["status"] = "success".into();
+    transaction["gateway_response"] = "Successful".into();
+    transaction["message"] = "Approved".into();
+    transaction["paid_at"] = timestamp().into();
+    transaction["fees"] = (amount * 15 / 1000).min(200_000).into();
+    transaction["authorization"] = authorization;
+}
+
+/// Marks a transaction as declined for lack of funds on the authorization
+fn decline(transaction: &mut Value, authorization: Value) {
+    transaction["status"] = "failed".into();
+    transaction["gateway_response"] = "Insufficient Funds".into();
+    transaction["message"] = "Declined".into();
+    transaction["fees"] = 0.into();
+    transaction["authorization"] = authorization;
+}
+
+/// Checks that the fields are present in the request body
+fn required(body: &Value, fields: &[&str]) -> Result<(), MockError> {
+    let missing: Vec<&str> = fields
+        .iter()
+        .copied()
+        .filter(|field| match body.get(field) {
+            None | Some(Value::Null) => true,
+            Some(Value::String(value)) => value.is_empty(),
+            _ => false,
+        })
+        .collect();
+
+    match missing.is_empty() {
+        true => Ok(()),
+        false => Err(MockError::missing(&missing)),
+    }
+}
+
+/// Reads a field of the request body, with a default for missing or null fields
+fn field(body: &Value, key: &str, default: impl Into<Value>) -> Value {
+    match body.get(key) {
+        None | Some(Value::Null) => default.into(),
+        Some(value) => value.clone(),
+    }
+}
+
+/// Reads a string or number field as a string
+fn text(value: &Value, key: &str) -> Option<String> {
+    match value.get(key)? {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        _ => None,
+    }
+}
+
+/// Reads an amount sent as a string or a number
+fn amount(body: &Value, key: &str) -> Result<u64, MockError> {
+    text(body, key)
+        .and_then(|amount| amount.parse().ok())
+        .ok_or_else(|| MockError::invalid(format!("Invalid {key}")))
+}
+
+/// Copies the given fields of the request body to a record
+fn merge(record: &mut Value, body: &Value, fields: &[&str]) {
+    for field in fields {
+        if let Some(value) = body.get(field).filter(|value| !value.is_null()) {
+            record[*field] = value.clone();
+        }
+    }
+    record["updatedAt"] = timestamp().into();
+}
+
+/// Checks if a record field matches a query parameter, missing parameters match everything
+fn matches(record: &Value, key: &str, expected: Option<&String>) -> bool {
+    let Some(expected) = expected else {
+        return true;
+    };
+    match &record[key] {
+        Value::String(value) => value == expected,
+        value => serde_json::from_str::<Value>(expected).is_ok_and(|expected| expected == *value),
+    }
+}
+
+fn has_id_or_code(record: &Value, code_key: &str, id_or_code: &str) -> bool {
+    has_id(record, id_or_code) || record[code_key] == id_or_code
+}
+
+fn has_id(record: &Value, id: &str) -> bool {
+    record["id"]
+        .as_u64()
+        .is_some_and(|record_id| id.parse() == Ok(record_id))
+}
+
+/// Creates a random code such as a customer code or transaction reference
+fn random_code(prefix: &str, len: usize) -> String {
+    const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+    let mut code = prefix.to_string();
+    while code.len() < prefix.len() + len {
+        let mut random = RandomState::new().build_hasher().finish();
+        while random > 0 && code.len() < prefix.len() + len {
+            code.push(CHARS[(random % CHARS.len() as u64) as usize] as char);
+            random /= CHARS.len() as u64;
+        }
+    }
+    code
+}
+
+/// Current time in the ISO 8601 format used by Paystack e.g. `2024-01-01T00:00:00.000Z`
+fn timestamp() -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let (secs, millis) = (now.as_secs(), now.subsec_millis());
+
+    // Convert days since the epoch to a civil date
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    let time = secs % 86_400;
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{millis:03}Z",
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60
+    )
+}
@@ -0,0 +1,292 @@
+//! Webhooks
+//! ========
+//! This file contains the helpers to verify and parse the events Paystack sends to your webhook URL.
+//!
+//! Every webhook request is signed with a HMAC SHA512 of the request body, keyed with your secret key,
+//! and sent in the `x-paystack-signature` header. Always verify the signature before trusting the payload.
+
+use crate::{PaystackAPIError, WebhookEvent, WebhookPayload};
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+/// Name of the header holding the signature of a webhook request
+pub const PAYSTACK_SIGNATURE_HEADER: &str = "x-paystack-signature";
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// Verifies the signature of a webhook request.
+///
+/// The comparison is done in constant time.
+///
+/// # Arguments
+/// * `secret_key` - Your Paystack secret key
+/// * `payload` - The raw, unmodified body of the webhook request
+/// * `signature` - The value of the `x-paystack-signature` header
+///
+/// # Returns
+/// `true` if the signature matches the payload, `false` otherwise
+pub fn verify_signature(secret_key: &str, payload: &[u8], signature: &str) -> bool {
+    let Ok(signature) = hex::decode(signature.trim()) else {
+        return false;
+    };
+
+    let Ok(mut mac) = HmacSha512::new_from_slice(secret_key.as_bytes()) else {
+        return false;
+    };
+    mac.update(payload);
+
+    mac.verify_slice(&signature).is_ok()
+}
+
+/// Parses the body of a webhook request into a typed event without verifying it.
+///
+/// Prefer `construct_event` unless the signature has already been verified.
+///
+/// # Arguments
+/// * `payload` - The raw body of the webhook request
+///
+/// # Returns
+/// A Result containing the typed webhook event or an error
+pub fn parse_event(payload: &[u8]) -> Result<WebhookEvent, PaystackAPIError> {
+    let payload: WebhookPayload =
+        serde_json::from_slice(payload).map_err(|e| PaystackAPIError::Webhook(e.to_string()))?;
+
+    WebhookEvent::from_payload(payload).map_err(|e| PaystackAPIError::Webhook(e.to_string()))
+}
+
+/// Verifies the signature of a webhook request and parses its body into a typed event.
+///
+/// # Arguments
+/// * `secret_key` - Your Paystack secret key
+/// * `payload` - The raw, unmodified body of the webhook request
+/// * `signature` - The value of the `x-paystack-signature` header
+///
+/// # Returns
+/// A Result containing the typed webhook event or an error if the signature is invalid
+pub fn construct_event(
+    secret_key: &str,
+    payload: &[u8],
+    signature: &str,
+) -> Result<WebhookEvent, PaystackAPIError> {
+    if !verify_signature(secret_key, payload, signature) {
+        return Err(PaystackAPIError::Webhook(
+            "invalid webhook signature".to_string(),
+        ));
+    }
+
+    parse_event(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET_KEY: &str = "sk_test_secret";
+
+    fn sign(payload: &[u8]) -> String {
+        let mut mac = HmacSha512::new_from_slice(SECRET_KEY.as_bytes()).unwrap();
+        mac.update(payload);
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    fn charge_success_payload() -> String {
+        serde_json::json!({
+            "event": "charge.success",
+            "data": {
+                "id": 302961,
+                "domain": "live",
+                "status": "success",
+                "reference": "qTPrJoy9Bx",
+                "amount": 10000,
+                "message": null,
+                "gateway_response": "Approved by Financial Institution",
+                "paid_at": "2016-09-30T21:10:19.000Z",
+                "created_at": "2016-09-30T21:09:56.000Z",
+                "channel": "card",
+                "currency": "NGN",
+                "ip_address": "41.242.49.37",
+                "metadata": "",
+                "fees": null,
+                "customer": {
+                    "id": 68324,
+                    "first_name": "BoJack",
+                    "last_name": "Horseman",
+                    "email": "bojack@horseman.com",
+                    "customer_code": "CUS_qo38as2hpsgk2r0",
+                    "phone": null,
+                    "metadata": null,
+                    "risk_action": "default"
+                },
+                "authorization": {
+                    "authorization_code": "AUTH_f5rnfq9p",
+                    "bin": "539999",
+                    "last4": "8877",
+                    "exp_month": "08",
+                    "exp_year": "2020",
+                    "card_type": "mastercard DEBIT",
+                    "bank": "Guaranty Trust Bank",
+                    "country_code": "NG",
+                    "brand": "mastercard",
+                    "account_name": "BoJack Horseman"
+                }
+            }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn can_verify_valid_signature() {
+        let payload = charge_success_payload();
+        let signature = sign(payload.as_bytes());
+
+        assert!(verify_signature(SECRET_KEY, payload.as_bytes(), &signature));
+    }
+
+    #[test]
+    fn rejects_tampered_payload_and_malformed_signature() {
+        let payload = charge_success_payload();
+        let signature = sign(payload.as_bytes());
+        let tampered = payload.replace("10000", "99999");
+
+        assert!(!verify_signature(
+            SECRET_KEY,
+            tampered.as_bytes(),
+            &signature
+        ));
+        assert!(!verify_signature(
+            "sk_test_other",
+            payload.as_bytes(),
+            &signature
+        ));
+        assert!(!verify_signature(SECRET_KEY, payload.as_bytes(), "not-hex"));
+    }
+
+    #[test]
+    fn can_construct_charge_success_event() -> Result<(), PaystackAPIError> {
+        let payload = charge_success_payload();
+        let signature = sign(payload.as_bytes());
+
+        let event = construct_event(SECRET_KEY, payload.as_bytes(), &signature)?;
+
+        assert_eq!(event.event_name(), "charge.success");
+        match event {
+            WebhookEvent::ChargeSuccess(data) => {
+                assert_eq!(data.reference, "qTPrJoy9Bx");
+                assert_eq!(data.customer.customer_code, "CUS_qo38as2hpsgk2r0");
+            }
+            _ => panic!("expected a charge.success event"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn construct_event_fails_with_invalid_signature() {
+        let payload = charge_success_payload();
+
+        let res = construct_event(SECRET_KEY, payload.as_bytes(), &"0".repeat(128));
+
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn can_parse_transfer_success_event() -> Result<(), PaystackAPIError> {
+        let payload = serde_json::json!({
+            "event": "transfer.success",
+            "data": {
+                "amount": 30000,
+                "currency": "NGN",
+                "domain": "test",
+                "failures": null,
+                "id": 37272792,
+                "integration": {
+                    "id": 463433,
+                    "is_live": true,
+                    "business_name": "Boom Boom Industries NG"
+                },
+                "reason": "Have fun...",
+                "reference": "1jhbs3ozmen0k7y5efmw",
+                "source": "balance",
+                "source_details": null,
+                "status": "success",
+                "titan_code": null,
+                "transfer_code": "TRF_wpl1dem4967avzm",
+                "transferred_at": null,
+                "recipient": {
+                    "recipient_code": "RCP_1i2k27vk4suemug",
+                    "type": "nuban"
+                },
+                "created_at": "2020-10-26T12:28:57.000Z",
+                "updated_at": "2020-10-26T12:28:57.000Z"
+            }
+        })
+        .to_string();
+
+        let event = parse_event(payload.as_bytes())?;
+
+        match event {
+            WebhookEvent::TransferSuccess(data) => {
+                assert_eq!(data.transfer_code, "TRF_wpl1dem4967avzm");
+                assert_eq!(data.integration, Some(463433));
+                assert_eq!(data.status, crate::TransferStatus::Success);
+                assert!(data.created_at.is_some());
+            }
+            _ => panic!("expected a transfer.success event"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_parse_refund_processed_event() -> Result<(), PaystackAPIError> {
+        let payload = serde_json::json!({
+            "event": "refund.processed",
+            "data": {
+                "status": "processed",
+                "transaction_reference": "1641",
+                "refund_reference": "132013318360",
+                "amount": 10000,
+                "currency": "NGN",
+                "processor": "mastercard",
+                "customer": {
+                    "first_name": "Damilola",
+                    "last_name": "Odujoko",
+                    "email": "damilola@example.com"
+                },
+                "integration": 463433,
+                "domain": "test"
+            }
+        })
+        .to_string();
+
+        let event = parse_event(payload.as_bytes())?;
+
+        match event {
+            WebhookEvent::RefundProcessed(data) => {
+                assert_eq!(data.transaction_reference, "1641");
+                assert_eq!(data.amount, 10000);
+                assert_eq!(data.customer.unwrap()["email"], "damilola@example.com");
+            }
+            _ => panic!("expected a refund.processed event"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_event_falls_through_to_raw_json() -> Result<(), PaystackAPIError> {
+        let payload = r#"{"event":"some.new_event","data":{"id":1}}"#;
+
+        let event = parse_event(payload.as_bytes())?;
+
+        match event {
+            WebhookEvent::Unknown { event, data } => {
+                assert_eq!(event, "some.new_event");
+                assert_eq!(data["id"], 1);
+            }
+            _ => panic!("expected an unknown event"),
+        }
+
+        Ok(())
+    }
+}
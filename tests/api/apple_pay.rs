@@ -1,3 +1,5 @@
+use paystack::ListApplePayDomainsFilterBuilder;
+
 use crate::helpers::get_paystack_client;
 
 #[tokio::test]
@@ -16,6 +18,27 @@ async fn can_list_domains() {
         .contains("Apple Pay registered domains retrieved"))
 }
 
+#[tokio::test]
+async fn can_list_domains_with_cursor() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let filter = ListApplePayDomainsFilterBuilder::default()
+        .use_cursor(true)
+        .build()
+        .unwrap();
+    let res = client
+        .apple_pay
+        .list_domains_with_filter(Some(filter))
+        .await
+        .expect("unable to list domains");
+
+    // Assert
+    assert!(res.status);
+    assert!(res.data.is_some());
+}
+
 #[tokio::test]
 async fn can_unregister_domain() {
     // Arrange
@@ -30,10 +53,8 @@ async fn can_unregister_domain() {
         .expect("unable to unregister domain");
 
     // Assert
-    assert!(res.status);
-    assert!(res
-        .message
-        .contains("Domain successfully unregistered on Apple Pay"));
+    assert_eq!(res.domain_name, "example.com");
+    assert!(!res.message.is_empty());
 }
 
 // TODO: need elevated permission for some tests on this route
@@ -0,0 +1,38 @@
+use paystack::ListBulkChargeBatchesFilterBuilder;
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_list_bulk_charge_batches() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let filter = ListBulkChargeBatchesFilterBuilder::default()
+        .per_page(10)
+        .build()
+        .unwrap();
+    let res = client
+        .bulk_charges
+        .list_bulk_charge_batches(Some(filter))
+        .await
+        .expect("unable to list bulk charge batches");
+
+    // Assert
+    assert!(res.status);
+}
+
+#[tokio::test]
+async fn fetch_bulk_charge_batch_fails_with_unknown_code() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let res = client
+        .bulk_charges
+        .fetch_bulk_charge_batch("BCH_unknown".to_string())
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+}
@@ -1,5 +1,7 @@
 use crate::helpers::get_paystack_client;
-use paystack::{Channel, ChargeRequestBuilder, Currency};
+use paystack::{
+    Channel, ChargeBank, ChargeRequestBuilder, ChargeStatus, Currency, DirectChargeRequestBuilder,
+};
 use rand::Rng;
 use std::error::Error;
 
@@ -38,3 +40,46 @@ async fn charge_authorization_succeeds() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn can_create_bank_charge() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let request = DirectChargeRequestBuilder::default()
+        .email("customer@email.com".to_string())
+        .amount("10000".to_string())
+        .bank(ChargeBank {
+            code: "057".to_string(),
+            account_number: "0000000000".to_string(),
+        })
+        .build()
+        .unwrap();
+    let res = client
+        .charges
+        .create_charge(request)
+        .await
+        .expect("unable to create charge");
+
+    // Assert
+    assert!(res.status);
+    let data = res.data.unwrap();
+    assert!(!data.reference.is_empty());
+    assert_ne!(data.status, ChargeStatus::Failed);
+}
+
+#[tokio::test]
+async fn check_pending_charge_fails_with_unknown_reference() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let res = client
+        .charges
+        .check_pending_charge("unknown-reference".to_string())
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+}
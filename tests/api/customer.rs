@@ -7,7 +7,8 @@ use fake::{
     Fake,
 };
 use paystack::{
-    CreateCustomerRequestBuilder, IdentificationType, RiskAction, UpdateCustomerRequestBuilder,
+    CreateCustomerRequestBuilder, IdentificationType, InitializeAuthorizationRequestBuilder,
+    ListCustomersFilterBuilder, RiskAction, UpdateCustomerRequestBuilder,
     ValidateCustomerRequestBuilder,
 };
 
@@ -283,4 +284,56 @@ async fn can_blacklist_and_whitelist_a_customer() {
     assert_eq!(res.data.unwrap().risk_action, Some(RiskAction::Default));
 }
 
+#[tokio::test]
+async fn can_list_customers_in_integration_by_date_range() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let filter = ListCustomersFilterBuilder::default()
+        .per_page(10)
+        .from("2020-01-01T00:00:00.000Z".to_string())
+        .to("2099-01-01T00:00:00.000Z".to_string())
+        .build()
+        .unwrap();
+    let res = client
+        .customers
+        .list_customers_with_filter(Some(filter))
+        .await
+        .expect("unable to list customers");
+
+    // Assert
+    assert!(res.status);
+    assert!(res.message.contains("Customers retrieved"));
+}
+
+#[tokio::test]
+async fn can_initialize_direct_debit_authorization() {
+    // Arrange
+    let client = get_paystack_client();
+    let customer = client
+        .customers
+        .list_customers(Some(1), Some(1))
+        .await
+        .expect("unable to get customer")
+        .data
+        .unwrap()
+        .remove(0);
+
+    // Act
+    let request = InitializeAuthorizationRequestBuilder::default()
+        .email(customer.email)
+        .build()
+        .unwrap();
+    let res = client
+        .customers
+        .initialize_authorization(request)
+        .await
+        .expect("unable to initialize authorization");
+
+    // Assert
+    assert!(res.status);
+    assert!(!res.data.unwrap().reference.is_empty());
+}
+
 // TODO: make test for dea
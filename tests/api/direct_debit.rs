@@ -0,0 +1,27 @@
+use paystack::{ListMandateAuthorizationsFilterBuilder, MandateStatus};
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_list_active_mandate_authorizations() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let filter = ListMandateAuthorizationsFilterBuilder::default()
+        .per_page(10)
+        .status(MandateStatus::Active)
+        .build()
+        .unwrap();
+    let res = client
+        .direct_debit
+        .list_mandate_authorizations(Some(filter))
+        .await
+        .expect("unable to list mandate authorizations");
+
+    // Assert
+    assert!(res.status);
+    for mandate in res.data.unwrap() {
+        assert_eq!(mandate.status, MandateStatus::Active);
+    }
+}
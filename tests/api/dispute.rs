@@ -0,0 +1,42 @@
+use paystack::{DisputeStatus, ListDisputesFilterBuilder};
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_list_disputes_in_the_integration() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let filter = ListDisputesFilterBuilder::default()
+        .per_page(10)
+        .status(DisputeStatus::AwaitingMerchantFeedback)
+        .build()
+        .unwrap();
+    let res = client
+        .disputes
+        .list_disputes(Some(filter))
+        .await
+        .expect("unable to list disputes");
+
+    // Assert
+    assert!(res.status);
+    assert_eq!(res.message, "Disputes retrieved");
+}
+
+#[tokio::test]
+async fn can_export_disputes_in_the_integration() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let res = client
+        .disputes
+        .export_disputes(None)
+        .await
+        .expect("unable to export disputes");
+
+    // Assert
+    assert!(res.status);
+    assert!(!res.data.unwrap().path.is_empty());
+}
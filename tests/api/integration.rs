@@ -0,0 +1,28 @@
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_fetch_and_update_payment_session_timeout() {
+    // Arrange
+    let client = get_paystack_client();
+    let current = client
+        .integration
+        .fetch_payment_session_timeout()
+        .await
+        .expect("unable to fetch payment session timeout")
+        .data
+        .unwrap();
+
+    // Act
+    let res = client
+        .integration
+        .update_payment_session_timeout(current.payment_session_timeout)
+        .await
+        .expect("unable to update payment session timeout");
+
+    // Assert
+    assert!(res.status);
+    assert_eq!(
+        res.data.unwrap().payment_session_timeout,
+        current.payment_session_timeout
+    );
+}
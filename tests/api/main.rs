@@ -1,11 +1,28 @@
 pub mod apple_pay;
+pub mod bulk_charge;
 pub mod charge;
 pub mod customer;
 pub mod dedicated_virtual_account;
+pub mod direct_debit;
+pub mod dispute;
 pub mod helpers;
+pub mod integration;
+pub mod miscellaneous;
+pub mod order;
+pub mod payment_page;
+pub mod payment_request;
 pub mod plans;
+pub mod product;
+pub mod refund;
+pub mod settlement;
+pub mod storefront;
 pub mod subaccount;
+pub mod subscription;
 pub mod terminal;
 pub mod transaction;
 pub mod transaction_split;
+pub mod transfer;
+pub mod transfer_control;
+pub mod transfer_recipient;
+pub mod verification;
 pub mod virtual_terminal;
@@ -0,0 +1,46 @@
+use paystack::{Currency, ListBanksFilterBuilder};
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_list_banks_by_currency() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let filter = ListBanksFilterBuilder::default()
+        .currency(Currency::NGN)
+        .build()
+        .unwrap();
+    let res = client
+        .miscellaneous
+        .list_banks(Some(filter))
+        .await
+        .expect("unable to list banks");
+
+    // Assert
+    assert!(res.status);
+    assert!(!res.data.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn can_list_countries_and_states() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let countries = client
+        .miscellaneous
+        .list_countries()
+        .await
+        .expect("unable to list countries");
+    let states = client
+        .miscellaneous
+        .list_states("CA".to_string())
+        .await
+        .expect("unable to list states");
+
+    // Assert
+    assert!(countries.status);
+    assert!(states.status);
+}
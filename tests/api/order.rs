@@ -0,0 +1,23 @@
+use paystack::ListOrdersFilterBuilder;
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_list_orders() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let filter = ListOrdersFilterBuilder::default()
+        .per_page(10)
+        .build()
+        .unwrap();
+    let res = client
+        .orders
+        .list_orders(Some(filter))
+        .await
+        .expect("unable to list orders");
+
+    // Assert
+    assert!(res.status);
+}
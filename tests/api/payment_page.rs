@@ -0,0 +1,69 @@
+use paystack::{PaymentPageRequestBuilder, PaymentPageUpdateRequestBuilder};
+use rand::Rng;
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_create_and_update_fixed_amount_payment_page() {
+    // Arrange
+    let client = get_paystack_client();
+    let slug = format!(
+        "test-page-{}",
+        rand::thread_rng().gen_range(100_000..999_999)
+    );
+
+    // Act
+    let request = PaymentPageRequestBuilder::default()
+        .name("Test Page".to_string())
+        .amount("100000".to_string())
+        .fixed_amount(true)
+        .slug(slug.clone())
+        .build()
+        .unwrap();
+    let res = client
+        .payment_pages
+        .create_payment_page(request)
+        .await
+        .expect("unable to create payment page");
+
+    let update = PaymentPageUpdateRequestBuilder::default()
+        .active(false)
+        .build()
+        .unwrap();
+    let updated = client
+        .payment_pages
+        .update_payment_page(slug.clone(), update)
+        .await
+        .expect("unable to update payment page");
+
+    // Assert
+    assert!(res.status);
+    assert_eq!(res.data.unwrap().slug, slug);
+    assert_eq!(updated.data.unwrap().active, Some(false));
+}
+
+#[tokio::test]
+async fn check_slug_availability_fails_for_taken_slug() {
+    // Arrange
+    let client = get_paystack_client();
+    let slug = format!(
+        "test-page-{}",
+        rand::thread_rng().gen_range(100_000..999_999)
+    );
+    let request = PaymentPageRequestBuilder::default()
+        .name("Test Page".to_string())
+        .slug(slug.clone())
+        .build()
+        .unwrap();
+    client
+        .payment_pages
+        .create_payment_page(request)
+        .await
+        .expect("unable to create payment page");
+
+    // Act
+    let res = client.payment_pages.check_slug_availability(slug).await;
+
+    // Assert
+    assert!(res.is_err());
+}
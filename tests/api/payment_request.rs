@@ -0,0 +1,62 @@
+use paystack::{CreatePaymentRequestBuilder, LineItemBuilder, TaxBuilder};
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_create_draft_payment_request() {
+    // Arrange
+    let client = get_paystack_client();
+    let customer = client
+        .customers
+        .list_customers(Some(1), Some(1))
+        .await
+        .expect("unable to list customers")
+        .data
+        .unwrap()
+        .remove(0);
+
+    // Act
+    let line_item = LineItemBuilder::default()
+        .name("Consulting".to_string())
+        .amount("500000".to_string())
+        .build()
+        .unwrap();
+    let tax = TaxBuilder::default()
+        .name("VAT".to_string())
+        .amount("37500".to_string())
+        .build()
+        .unwrap();
+    let request = CreatePaymentRequestBuilder::default()
+        .customer(customer)
+        .description("Consulting services".to_string())
+        .line_items(vec![line_item])
+        .tax(vec![tax])
+        .draft(true)
+        .build()
+        .unwrap();
+    let res = client
+        .payment_requests
+        .create_payment_request(request)
+        .await
+        .expect("unable to create payment request");
+
+    // Assert
+    assert!(res.status);
+    assert!(res.data.unwrap().request_code.starts_with("PRQ_"));
+}
+
+#[tokio::test]
+async fn can_fetch_payment_request_totals() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let res = client
+        .payment_requests
+        .payment_request_totals()
+        .await
+        .expect("unable to fetch payment request totals");
+
+    // Assert
+    assert!(res.status);
+}
@@ -0,0 +1,58 @@
+use fake::{faker::lorem::en::Sentence, Fake};
+use paystack::{Currency, ProductRequestBuilder, ProductUpdateRequestBuilder};
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_create_and_update_product() {
+    // Arrange
+    let client = get_paystack_client();
+    let description: String = Sentence(5..10).fake();
+
+    // Act
+    let request = ProductRequestBuilder::default()
+        .name("Test Product".to_string())
+        .description(description)
+        .price("50000".to_string())
+        .currency(Currency::NGN)
+        .unlimited(true)
+        .build()
+        .unwrap();
+    let res = client
+        .products
+        .create_product(request)
+        .await
+        .expect("unable to create product");
+    let product = res.data.unwrap();
+
+    let update = ProductUpdateRequestBuilder::default()
+        .price("60000".to_string())
+        .build()
+        .unwrap();
+    let updated = client
+        .products
+        .update_product(product.id, update)
+        .await
+        .expect("unable to update product");
+
+    // Assert
+    assert!(res.status);
+    assert_eq!(product.currency, Currency::NGN);
+    assert_eq!(updated.data.unwrap().price, 60000);
+}
+
+#[tokio::test]
+async fn can_list_products() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let res = client
+        .products
+        .list_products(None)
+        .await
+        .expect("unable to list products");
+
+    // Assert
+    assert!(res.status);
+}
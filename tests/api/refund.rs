@@ -0,0 +1,41 @@
+use paystack::{Currency, ListRefundsFilterBuilder, RefundRequestBuilder};
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_list_refunds_in_the_integration() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let filter = ListRefundsFilterBuilder::default()
+        .per_page(10)
+        .currency(Currency::NGN)
+        .build()
+        .unwrap();
+    let res = client
+        .refunds
+        .list_refunds(Some(filter))
+        .await
+        .expect("unable to list refunds");
+
+    // Assert
+    assert!(res.status);
+    assert_eq!(res.message, "Refunds retrieved");
+}
+
+#[tokio::test]
+async fn create_refund_fails_with_unknown_transaction() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let body = RefundRequestBuilder::default()
+        .transaction("unknown-transaction-reference".to_string())
+        .build()
+        .unwrap();
+    let res = client.refunds.create_refund(body).await;
+
+    // Assert
+    assert!(res.is_err());
+}
@@ -0,0 +1,27 @@
+use paystack::{ListSettlementsFilterBuilder, SettlementStatus};
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_list_settlements() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let filter = ListSettlementsFilterBuilder::default()
+        .per_page(10)
+        .status(SettlementStatus::Success)
+        .build()
+        .unwrap();
+    let res = client
+        .settlements
+        .list_settlements(Some(filter))
+        .await
+        .expect("unable to list settlements");
+
+    // Assert
+    assert!(res.status);
+    for settlement in res.data.unwrap() {
+        assert_eq!(settlement.status, SettlementStatus::Success);
+    }
+}
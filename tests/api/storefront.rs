@@ -0,0 +1,40 @@
+use paystack::{Currency, StorefrontRequestBuilder};
+use rand::Rng;
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_create_and_delete_storefront() {
+    // Arrange
+    let client = get_paystack_client();
+    let slug = format!(
+        "test-store-{}",
+        rand::thread_rng().gen_range(100_000..999_999)
+    );
+
+    // Act
+    let request = StorefrontRequestBuilder::default()
+        .name("Test Store".to_string())
+        .slug(slug.clone())
+        .currency(Currency::NGN)
+        .build()
+        .unwrap();
+    let res = client
+        .storefronts
+        .create_storefront(request)
+        .await
+        .expect("unable to create storefront");
+    let storefront = res.data.unwrap();
+
+    let deleted = client
+        .storefronts
+        .delete_storefront(storefront.id)
+        .await
+        .expect("unable to delete storefront");
+
+    // Assert
+    assert!(res.status);
+    assert_eq!(storefront.slug, slug);
+    assert_eq!(storefront.currency, Currency::NGN);
+    assert!(deleted.status);
+}
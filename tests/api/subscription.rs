@@ -0,0 +1,39 @@
+use paystack::ListSubscriptionsFilterBuilder;
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_list_subscriptions_in_the_integration() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let filter = ListSubscriptionsFilterBuilder::default()
+        .per_page(10)
+        .build()
+        .unwrap();
+    let res = client
+        .subscriptions
+        .list_subscriptions(Some(filter))
+        .await
+        .expect("unable to list subscriptions");
+
+    // Assert
+    assert!(res.status);
+    assert_eq!(res.message, "Subscriptions retrieved");
+}
+
+#[tokio::test]
+async fn fetch_subscription_fails_with_unknown_code() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let res = client
+        .subscriptions
+        .fetch_subscription("SUB_unknown".to_string())
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+}
@@ -0,0 +1,58 @@
+use paystack::{ListTransfersFilterBuilder, TransferRequestBuilder};
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_list_transfers_in_the_integration() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let filter = ListTransfersFilterBuilder::default()
+        .per_page(10)
+        .page(1)
+        .build()
+        .unwrap();
+    let res = client
+        .transfers
+        .list_transfers(Some(filter))
+        .await
+        .expect("unable to list transfers");
+
+    // Assert
+    assert!(res.status);
+    assert_eq!(res.message, "Transfers retrieved");
+}
+
+#[tokio::test]
+async fn initiate_transfer_fails_with_invalid_recipient() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let body = TransferRequestBuilder::default()
+        .amount("10000".to_string())
+        .recipient("RCP_invalid_recipient".to_string())
+        .reason("Test transfer".to_string())
+        .build()
+        .unwrap();
+    let res = client.transfers.initiate_transfer(body).await;
+
+    // Assert
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn verify_transfer_fails_with_unknown_reference() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let res = client
+        .transfers
+        .verify_transfer("unknown-transfer-reference".to_string())
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+}
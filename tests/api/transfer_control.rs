@@ -0,0 +1,41 @@
+use paystack::ListBalanceLedgerFilterBuilder;
+
+use crate::helpers::get_paystack_client;
+
+#[tokio::test]
+async fn can_check_balance() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let res = client
+        .transfer_control
+        .check_balance()
+        .await
+        .expect("unable to check balance");
+
+    // Assert
+    assert!(res.status);
+    assert_eq!(res.message, "Balances retrieved");
+    assert!(!res.data.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn can_fetch_balance_ledger() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let filter = ListBalanceLedgerFilterBuilder::default()
+        .per_page(10)
+        .build()
+        .unwrap();
+    let res = client
+        .transfer_control
+        .fetch_balance_ledger(Some(filter))
+        .await
+        .expect("unable to fetch balance ledger");
+
+    // Assert
+    assert!(res.status);
+}
@@ -0,0 +1,103 @@
+use fake::{faker::name::en::Name, Fake};
+use paystack::{
+    Currency, ListTransferRecipientsFilterBuilder, RecipientType, TransferRecipientRequestBuilder,
+};
+
+use crate::helpers::{get_bank_account_number_and_code, get_paystack_client};
+
+#[tokio::test]
+async fn can_create_nuban_transfer_recipient() {
+    // Arrange
+    let client = get_paystack_client();
+    let (account_number, bank_code, _) = get_bank_account_number_and_code();
+
+    // Act
+    let name: String = Name().fake();
+    let body = TransferRecipientRequestBuilder::default()
+        .recipient_type(RecipientType::Nuban)
+        .name(name)
+        .account_number(account_number)
+        .bank_code(bank_code)
+        .currency(Currency::NGN)
+        .build()
+        .unwrap();
+
+    let res = client
+        .transfer_recipients
+        .create_transfer_recipient(body)
+        .await
+        .expect("unable to create transfer recipient");
+
+    // Assert
+    assert!(res.status);
+    let data = res.data.unwrap();
+    assert_eq!(data.recipient_type, RecipientType::Nuban);
+    assert!(data.recipient_code.starts_with("RCP_"));
+}
+
+#[tokio::test]
+async fn can_list_transfer_recipients() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let filter = ListTransferRecipientsFilterBuilder::default()
+        .per_page(10)
+        .build()
+        .unwrap();
+    let res = client
+        .transfer_recipients
+        .list_transfer_recipients(Some(filter))
+        .await
+        .expect("unable to list transfer recipients");
+
+    // Assert
+    assert!(res.status);
+    assert_eq!(res.message, "Recipients retrieved");
+}
+
+#[tokio::test]
+async fn can_fetch_update_and_delete_transfer_recipient() {
+    // Arrange
+    let client = get_paystack_client();
+    let (account_number, bank_code, _) = get_bank_account_number_and_code();
+    let name: String = Name().fake();
+    let body = TransferRecipientRequestBuilder::default()
+        .recipient_type(RecipientType::Nuban)
+        .name(name)
+        .account_number(account_number)
+        .bank_code(bank_code)
+        .build()
+        .unwrap();
+    let recipient = client
+        .transfer_recipients
+        .create_transfer_recipient(body)
+        .await
+        .expect("unable to create transfer recipient")
+        .data
+        .unwrap();
+
+    // Act
+    let fetched = client
+        .transfer_recipients
+        .fetch_transfer_recipient(recipient.recipient_code.clone())
+        .await
+        .expect("unable to fetch transfer recipient");
+    let new_name: String = Name().fake();
+    let updated = client
+        .transfer_recipients
+        .update_transfer_recipient(recipient.recipient_code.clone(), new_name, None)
+        .await
+        .expect("unable to update transfer recipient");
+    let deleted = client
+        .transfer_recipients
+        .delete_transfer_recipient(recipient.recipient_code.clone())
+        .await
+        .expect("unable to delete transfer recipient");
+
+    // Assert
+    assert!(fetched.status);
+    assert_eq!(fetched.data.unwrap().id, recipient.id);
+    assert!(updated.status);
+    assert!(deleted.status);
+}
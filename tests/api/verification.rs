@@ -0,0 +1,38 @@
+use crate::helpers::{get_bank_account_number_and_code, get_paystack_client};
+
+#[tokio::test]
+async fn can_resolve_account_number() {
+    // Arrange
+    let client = get_paystack_client();
+    let (account_number, bank_code, _) = get_bank_account_number_and_code();
+
+    // Act
+    let res = client
+        .verification
+        .resolve_account_number(account_number.clone(), bank_code)
+        .await
+        .expect("unable to resolve account number");
+
+    // Assert
+    assert!(res.status);
+    let data = res.data.unwrap();
+    assert_eq!(data.account_number, account_number);
+    assert!(!data.account_name.is_empty());
+}
+
+#[tokio::test]
+async fn can_resolve_card_bin() {
+    // Arrange
+    let client = get_paystack_client();
+
+    // Act
+    let res = client
+        .verification
+        .resolve_card_bin("539983".to_string())
+        .await
+        .expect("unable to resolve card bin");
+
+    // Assert
+    assert!(res.status);
+    assert_eq!(res.data.unwrap().bin, "539983");
+}
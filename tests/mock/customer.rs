@@ -0,0 +1,111 @@
+use paystack::{CreateCustomerRequestBuilder, RiskAction, UpdateCustomerRequestBuilder};
+
+use crate::helpers::get_mock_paystack_client;
+
+#[tokio::test]
+async fn customers_are_kept_by_the_mock_server() {
+    // Arrange
+    let (_server, client) = get_mock_paystack_client().await;
+    let body = CreateCustomerRequestBuilder::default()
+        .email("customer@example.com".to_string())
+        .first_name("Old First Name".to_string())
+        .build()
+        .unwrap();
+
+    // Act
+    let customer = client
+        .customers
+        .create_customer(body)
+        .await
+        .expect("unable to create customer")
+        .data
+        .unwrap();
+
+    let update_request = UpdateCustomerRequestBuilder::default()
+        .first_name("New First Name".to_string())
+        .build()
+        .unwrap();
+    client
+        .customers
+        .update_customer(customer.customer_code.clone(), update_request)
+        .await
+        .expect("unable to update customer");
+
+    let res = client
+        .customers
+        .fetch_customer(customer.email.clone())
+        .await
+        .expect("unable to fetch customer");
+
+    // Assert
+    let data = res.data.unwrap();
+    assert!(res.status);
+    assert_eq!(res.message, "Customer retrieved");
+    assert!(data.customer_code.starts_with("CUS_"));
+    assert_eq!(data.customer_code, customer.customer_code);
+    assert_eq!(data.first_name.unwrap(), "New First Name");
+}
+
+#[tokio::test]
+async fn customers_are_listed_with_pagination_meta() {
+    // Arrange
+    let (_server, client) = get_mock_paystack_client().await;
+    for i in 0..3 {
+        let body = CreateCustomerRequestBuilder::default()
+            .email(format!("customer{i}@example.com"))
+            .build()
+            .unwrap();
+        client.customers.create_customer(body).await.unwrap();
+    }
+
+    // Act
+    let res = client
+        .customers
+        .list_customers(Some(2), Some(2))
+        .await
+        .expect("unable to list customers");
+
+    // Assert
+    let meta = res.meta.unwrap();
+    assert_eq!(res.data.unwrap().len(), 1);
+    assert_eq!(meta.total, Some(3));
+    assert_eq!(meta.page_count, Some(2));
+}
+
+#[tokio::test]
+async fn customer_risk_action_can_be_set() {
+    // Arrange
+    let (_server, client) = get_mock_paystack_client().await;
+    let body = CreateCustomerRequestBuilder::default()
+        .email("customer@example.com".to_string())
+        .build()
+        .unwrap();
+    let customer = client.customers.create_customer(body).await.unwrap();
+
+    // Act
+    let res = client
+        .customers
+        .whitelist_or_blacklist_customer(customer.data.unwrap().customer_code, RiskAction::Deny)
+        .await
+        .expect("unable to set customer risk action");
+
+    // Assert
+    assert_eq!(res.data.unwrap().risk_action, Some(RiskAction::Deny));
+}
+
+#[tokio::test]
+async fn fetching_an_unknown_customer_fails_with_not_found() {
+    // Arrange
+    let (_server, client) = get_mock_paystack_client().await;
+
+    // Act
+    let res = client
+        .customers
+        .fetch_customer("CUS_unknown".to_string())
+        .await;
+
+    // Assert
+    let error = res.expect_err("expected an error");
+    assert!(error.is_not_found());
+    assert_eq!(error.api_error().unwrap().message, "Customer not found");
+}
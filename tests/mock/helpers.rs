@@ -0,0 +1,14 @@
+use paystack::testing::MockPaystackServer;
+use paystack::{PaystackClient, ReqwestClient};
+
+/// A function to start a mock Paystack server and get a paystack client pointed at it
+pub async fn get_mock_paystack_client() -> (MockPaystackServer, PaystackClient<ReqwestClient>) {
+    let server = MockPaystackServer::start()
+        .await
+        .expect("Unable to start the mock Paystack server");
+    let client = server
+        .client()
+        .expect("Unable to create a paystack client for the mock server");
+
+    (server, client)
+}
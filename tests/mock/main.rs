@@ -0,0 +1,5 @@
+pub mod customer;
+pub mod helpers;
+pub mod plans;
+pub mod transaction;
+pub mod transaction_split;
@@ -0,0 +1,57 @@
+use paystack::{Interval, PlanRequestBuilder, PlanUpdateRequestBuilder};
+
+use crate::helpers::get_mock_paystack_client;
+
+#[tokio::test]
+async fn plans_can_be_created_updated_and_listed() {
+    // Arrange
+    let (_server, client) = get_mock_paystack_client().await;
+    let body = PlanRequestBuilder::default()
+        .name("Monthly Plan".to_string())
+        .interval(Interval::Monthly)
+        .amount("50000".to_string())
+        .description("Monthly subscription".to_string())
+        .build()
+        .unwrap();
+
+    // Act
+    let plan = client
+        .plans
+        .create_plan(body)
+        .await
+        .expect("unable to create plan")
+        .data
+        .unwrap();
+
+    let update = PlanUpdateRequestBuilder::default()
+        .name("Annual Plan".to_string())
+        .interval(Interval::Annually)
+        .build()
+        .unwrap();
+    let updated = client
+        .plans
+        .update_plan(plan.plan_code.clone(), update)
+        .await
+        .expect("unable to update plan");
+
+    let fetched = client
+        .plans
+        .fetch_plan(plan.id.to_string())
+        .await
+        .expect("unable to fetch plan")
+        .data
+        .unwrap();
+    let monthly = client
+        .plans
+        .list_plans(None, None, None, Some(Interval::Monthly), None)
+        .await
+        .expect("unable to list plans");
+
+    // Assert
+    assert!(plan.plan_code.starts_with("PLN_"));
+    assert_eq!(plan.amount, 50000);
+    assert!(updated.status);
+    assert_eq!(fetched.name, "Annual Plan");
+    assert_eq!(fetched.interval, Interval::Annually);
+    assert!(monthly.data.unwrap().is_empty());
+}
@@ -0,0 +1,315 @@
+use paystack::{
+    ChargeRequestBuilder, Currency, PartialDebitTransactionRequestBuilder, PaystackClient,
+    ReqwestClient, Status, TransactionIdentifier, TransactionRequestBuilder,
+};
+
+use crate::helpers::get_mock_paystack_client;
+
+#[tokio::test]
+async fn payment_flow_runs_against_the_mock_server() {
+    // Arrange
+    let (server, client) = get_mock_paystack_client().await;
+    let body = TransactionRequestBuilder::default()
+        .amount("10000".to_string())
+        .email("customer@example.com".to_string())
+        .currency(Currency::NGN)
+        .build()
+        .unwrap();
+
+    // Act
+    let transaction = client
+        .transactions
+        .initialize_transaction(body)
+        .await
+        .expect("unable to initialize transaction")
+        .data
+        .unwrap();
+    let pending = client
+        .transactions
+        .verify_transaction(&transaction.reference)
+        .await
+        .expect("unable to verify transaction");
+
+    assert!(server.complete_transaction(&transaction.reference));
+    let verified = client
+        .transactions
+        .verify_transaction(&transaction.reference)
+        .await
+        .expect("unable to verify transaction")
+        .data
+        .unwrap();
+
+    let authorization_code = verified.authorization.authorization_code.unwrap();
+    let charge = ChargeRequestBuilder::default()
+        .email(verified.customer.email.clone())
+        .amount("5000".to_string())
+        .authorization_code(authorization_code.clone())
+        .build()
+        .unwrap();
+    let charged = client
+        .transactions
+        .charge_authorization(charge)
+        .await
+        .expect("unable to charge authorization")
+        .data
+        .unwrap();
+
+    let partial_debit = PartialDebitTransactionRequestBuilder::default()
+        .email(verified.customer.email.clone())
+        .amount("2500".to_string())
+        .authorization_code(authorization_code)
+        .currency(Currency::NGN)
+        .build()
+        .unwrap();
+    client
+        .transactions
+        .partial_debit(partial_debit)
+        .await
+        .expect("unable to partially debit authorization");
+
+    let totals = client
+        .transactions
+        .total_transactions()
+        .await
+        .expect("unable to get transaction totals")
+        .data
+        .unwrap();
+
+    // Assert
+    assert_eq!(pending.data.unwrap().status, Status::Abandoned.to_string());
+    assert!(transaction
+        .authorization_url
+        .ends_with(&transaction.access_code));
+    assert_eq!(verified.status, Status::Success.to_string());
+    assert_eq!(verified.amount, 10000);
+    assert_eq!(charged.status, Status::Success.to_string());
+    assert_eq!(charged.amount, 5000);
+    assert_eq!(totals.total_transactions, Some(3));
+    assert_eq!(totals.unique_customers, Some(1));
+    assert_eq!(totals.total_volume, Some(17500));
+}
+
+#[tokio::test]
+async fn transactions_are_listed_by_status() {
+    // Arrange
+    let (server, client) = get_mock_paystack_client().await;
+    for i in 0..3 {
+        let body = TransactionRequestBuilder::default()
+            .amount("10000".to_string())
+            .email(format!("customer{i}@example.com"))
+            .build()
+            .unwrap();
+        let transaction = client
+            .transactions
+            .initialize_transaction(body)
+            .await
+            .unwrap();
+        if i > 0 {
+            server.complete_transaction(&transaction.data.unwrap().reference);
+        }
+    }
+
+    // Act
+    let res = client
+        .transactions
+        .list_transactions(None, Some(Status::Success))
+        .await
+        .expect("unable to list transactions");
+
+    // Assert
+    let data = res.data.unwrap();
+    assert_eq!(data.len(), 2);
+    assert!(data.iter().all(|t| t.status == Status::Success.to_string()));
+}
+
+#[tokio::test]
+async fn duplicate_transaction_references_are_rejected() {
+    // Arrange
+    let (_server, client) = get_mock_paystack_client().await;
+    let body = || {
+        TransactionRequestBuilder::default()
+            .amount("10000".to_string())
+            .email("customer@example.com".to_string())
+            .reference("order-1".to_string())
+            .build()
+            .unwrap()
+    };
+
+    // Act
+    client
+        .transactions
+        .initialize_transaction(body())
+        .await
+        .expect("unable to initialize transaction");
+    let res = client.transactions.initialize_transaction(body()).await;
+
+    // Assert
+    let error = res.expect_err("expected an error");
+    assert_eq!(error.api_error().unwrap().status, 400);
+    assert_eq!(
+        error.api_error().unwrap().message,
+        "Duplicate Transaction Reference"
+    );
+}
+
+#[tokio::test]
+async fn missing_fields_are_reported_as_validation_errors() {
+    // Arrange
+    let (_server, client) = get_mock_paystack_client().await;
+    let body = TransactionRequestBuilder::default()
+        .amount("10000".to_string())
+        .email("".to_string())
+        .build()
+        .unwrap();
+
+    // Act
+    let res = client.transactions.initialize_transaction(body).await;
+
+    // Assert
+    let error = res.expect_err("expected an error");
+    let api_error = error.api_error().unwrap();
+    assert_eq!(api_error.validation_errors.len(), 1);
+    assert_eq!(
+        api_error.validation_errors[0].field.as_deref(),
+        Some("email")
+    );
+    assert_eq!(
+        api_error.validation_errors[0].rule.as_deref(),
+        Some("required")
+    );
+}
+
+#[tokio::test]
+async fn requests_without_a_secret_key_are_rejected() {
+    // Arrange
+    let (server, _) = get_mock_paystack_client().await;
+    let client = PaystackClient::<ReqwestClient>::builder("pk_test_public".to_string())
+        .base_url(server.base_url())
+        .build()
+        .unwrap();
+
+    // Act
+    let res = client.transactions.total_transactions().await;
+
+    // Assert
+    let error = res.expect_err("expected an error");
+    assert!(error.is_auth_error());
+}
+
+#[tokio::test]
+async fn partial_debit_takes_the_available_funds() {
+    // Arrange
+    let (server, client) = get_mock_paystack_client().await;
+    let body = TransactionRequestBuilder::default()
+        .amount("10000".to_string())
+        .email("customer@example.com".to_string())
+        .build()
+        .unwrap();
+    let reference = client
+        .transactions
+        .initialize_transaction(body)
+        .await
+        .unwrap()
+        .data
+        .unwrap()
+        .reference;
+    server.complete_transaction(&reference);
+    let authorization_code = client
+        .transactions
+        .verify_transaction(&reference)
+        .await
+        .unwrap()
+        .data
+        .unwrap()
+        .authorization
+        .authorization_code
+        .unwrap();
+    assert!(server.set_available_funds(&authorization_code, 3000));
+
+    let partial_debit = |at_least: &str| {
+        PartialDebitTransactionRequestBuilder::default()
+            .email("customer@example.com".to_string())
+            .amount("5000".to_string())
+            .at_least(Some(at_least.to_string()))
+            .authorization_code(authorization_code.clone())
+            .currency(Currency::NGN)
+            .build()
+            .unwrap()
+    };
+    let charge = ChargeRequestBuilder::default()
+        .email("customer@example.com".to_string())
+        .amount("5000".to_string())
+        .authorization_code(authorization_code.clone())
+        .build()
+        .unwrap();
+
+    // Act
+    let declined_charge = client
+        .transactions
+        .charge_authorization(charge)
+        .await
+        .expect("unable to charge authorization")
+        .data
+        .unwrap();
+    let debited = client
+        .transactions
+        .partial_debit(partial_debit("2000"))
+        .await
+        .expect("unable to partially debit authorization")
+        .data
+        .unwrap();
+    let declined_debit = client
+        .transactions
+        .partial_debit(partial_debit("2000"))
+        .await
+        .expect("unable to partially debit authorization")
+        .data
+        .unwrap();
+
+    // Assert
+    assert_eq!(declined_charge.status, Status::Failed.to_string());
+    assert_eq!(debited.status, Status::Success.to_string());
+    assert_eq!(debited.amount, 3000);
+    assert_eq!(declined_debit.status, Status::Failed.to_string());
+}
+
+#[tokio::test]
+async fn transaction_timeline_and_export_are_available() {
+    // Arrange
+    let (server, client) = get_mock_paystack_client().await;
+    let body = TransactionRequestBuilder::default()
+        .amount("10000".to_string())
+        .email("customer@example.com".to_string())
+        .build()
+        .unwrap();
+    let reference = client
+        .transactions
+        .initialize_transaction(body)
+        .await
+        .unwrap()
+        .data
+        .unwrap()
+        .reference;
+    server.complete_transaction(&reference);
+
+    // Act
+    let timeline = client
+        .transactions
+        .view_transaction_timeline(TransactionIdentifier::Reference(reference))
+        .await
+        .expect("unable to view transaction timeline")
+        .data
+        .unwrap();
+    let export = client
+        .transactions
+        .export_transaction(None, None, None)
+        .await
+        .expect("unable to export transactions")
+        .data
+        .unwrap();
+
+    // Assert
+    assert_eq!(timeline.success, Some(true));
+    assert_eq!(timeline.history.unwrap().len(), 2);
+    assert!(export.path.ends_with(".csv"));
+}
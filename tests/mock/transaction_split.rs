@@ -0,0 +1,175 @@
+use paystack::{
+    BearerType, CreateSubaccountRequestBuilder, Currency, DeleteSubAccountBody, PaystackClient,
+    ReqwestClient, SplitType, SubaccountBody, SubaccountBodyBuilder,
+    TransactionSplitRequestBuilder, UpdateTransactionSplitRequestBuilder,
+};
+
+use crate::helpers::get_mock_paystack_client;
+
+async fn create_subaccount_body(
+    client: &PaystackClient<ReqwestClient>,
+    business_name: &str,
+    share: f32,
+) -> SubaccountBody {
+    let body = CreateSubaccountRequestBuilder::default()
+        .business_name(business_name.to_string())
+        .settlement_bank("058".to_string())
+        .account_number("0123456047".to_string())
+        .percentage_charge(10.0)
+        .description(format!("{business_name} subaccount"))
+        .build()
+        .unwrap();
+
+    let subaccount = client
+        .subaccount
+        .create_subaccount(body)
+        .await
+        .expect("Unable to Create a subaccount");
+
+    SubaccountBodyBuilder::default()
+        .share(share)
+        .subaccount(subaccount.data.unwrap().subaccount_code)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn transaction_splits_are_kept_with_their_subaccounts() {
+    // Arrange
+    let (_server, client) = get_mock_paystack_client().await;
+    let first = create_subaccount_body(&client, "First Business", 60.0).await;
+    let second = create_subaccount_body(&client, "Second Business", 30.0).await;
+    let third = create_subaccount_body(&client, "Third Business", 10.0).await;
+
+    let body = TransactionSplitRequestBuilder::default()
+        .name("Marketplace Split".to_string())
+        .split_type(SplitType::Percentage)
+        .currency(Currency::NGN)
+        .bearer_type(BearerType::Subaccount)
+        .subaccounts(vec![first.clone(), second.clone()])
+        .bearer_subaccount(first.subaccount.clone())
+        .build()
+        .unwrap();
+
+    // Act
+    let split = client
+        .transaction_split
+        .create_transaction_split(body)
+        .await
+        .expect("Failed to create transaction split")
+        .data
+        .unwrap();
+    let split_id = split.id.to_string();
+
+    client
+        .transaction_split
+        .add_or_update_subaccount_split(&split_id, third.clone())
+        .await
+        .expect("Failed to add subaccount to transaction split");
+    client
+        .transaction_split
+        .remove_subaccount_from_transaction_split(
+            &split_id,
+            DeleteSubAccountBody {
+                subaccount: second.subaccount.clone(),
+            },
+        )
+        .await
+        .expect("Failed to remove subaccount from transaction split");
+
+    let fetched = client
+        .transaction_split
+        .fetch_transaction_split(&split_id)
+        .await
+        .expect("Failed to fetch transaction split")
+        .data
+        .unwrap();
+    let listed = client
+        .transaction_split
+        .list_transaction_splits(Some("Marketplace Split"), None)
+        .await
+        .expect("Failed to list transaction splits")
+        .data
+        .unwrap();
+
+    // Assert
+    assert!(split.split_code.starts_with("SPL_"));
+    assert_eq!(split.total_subaccounts, 2);
+    assert_eq!(listed.len(), 1);
+    assert_eq!(fetched.total_subaccounts, 2);
+    let codes: Vec<&str> = fetched
+        .subaccounts
+        .iter()
+        .map(|s| s.subaccount.subaccount_code.as_str())
+        .collect();
+    assert_eq!(
+        codes,
+        [first.subaccount.as_str(), third.subaccount.as_str()]
+    );
+}
+
+#[tokio::test]
+async fn transaction_split_can_be_updated() {
+    // Arrange
+    let (_server, client) = get_mock_paystack_client().await;
+    let first = create_subaccount_body(&client, "First Business", 80.0).await;
+    let body = TransactionSplitRequestBuilder::default()
+        .name("Old Split".to_string())
+        .split_type(SplitType::Flat)
+        .currency(Currency::NGN)
+        .bearer_type(BearerType::Account)
+        .subaccounts(vec![first.clone()])
+        .bearer_subaccount(first.subaccount.clone())
+        .build()
+        .unwrap();
+    let split = client
+        .transaction_split
+        .create_transaction_split(body)
+        .await
+        .expect("Failed to create transaction split")
+        .data
+        .unwrap();
+
+    // Act
+    let update_split_body = UpdateTransactionSplitRequestBuilder::default()
+        .active(false)
+        .name("New Split".to_string())
+        .build()
+        .unwrap();
+    let res = client
+        .transaction_split
+        .update_transaction_split(&split.id.to_string(), update_split_body)
+        .await
+        .expect("Failed to update transaction split");
+
+    // Assert
+    let data = res.data.unwrap();
+    assert_eq!(res.message, "Split group updated");
+    assert!(!data.active.unwrap());
+    assert_eq!(data.name, "New Split");
+}
+
+#[tokio::test]
+async fn transaction_split_with_unknown_subaccount_is_rejected() {
+    // Arrange
+    let (_server, client) = get_mock_paystack_client().await;
+    let body = TransactionSplitRequestBuilder::default()
+        .name("Split".to_string())
+        .split_type(SplitType::Percentage)
+        .currency(Currency::NGN)
+        .bearer_type(BearerType::Subaccount)
+        .subaccounts(vec![])
+        .bearer_subaccount("non_existent_subaccount".to_string())
+        .build()
+        .unwrap();
+
+    // Act
+    let res = client
+        .transaction_split
+        .create_transaction_split(body)
+        .await;
+
+    // Assert
+    let error = res.expect_err("expected an error");
+    assert_eq!(error.api_error().unwrap().status, 400);
+}